@@ -1,13 +1,340 @@
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use thiserror::Error;
 
+use crate::oanda::Transaction;
+
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("error making request: {}", 0.to_string())]
+    #[error("error making request: {0}")]
     Request(reqwest::Error),
 
-    #[error("request failed with status code: {}", 0)]
-    StatusNotOK(reqwest::StatusCode),
+    #[error("unauthorized: {0}")]
+    Unauthorized(Box<ApiError>),
+
+    #[error("forbidden: {0}")]
+    Forbidden(Box<ApiError>),
+
+    #[error("not found: {0}")]
+    NotFound(Box<ApiError>),
+
+    #[error("rate limited: {error}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        error: Box<ApiError>,
+    },
+
+    #[error("validation failed: {0}")]
+    Validation(Box<ApiError>),
 
-    #[error("error deserializing request json: {}", 0.to_string())]
+    #[error("server error ({status}): {error}")]
+    Server {
+        status: StatusCode,
+        error: Box<ApiError>,
+    },
+
+    #[error("unexpected status code ({status}): {error}")]
+    Status {
+        status: StatusCode,
+        error: Box<ApiError>,
+    },
+
+    #[error("invalid client configuration: {0}")]
+    Config(String),
+
+    #[error("invalid request parameters: {0}")]
+    Params(String),
+
+    #[error("error deserializing request json: {0}")]
     Deserialize(reqwest::Error),
+
+    #[error("error deserializing stream message: {0}")]
+    StreamDeserialize(serde_json::Error),
+
+    #[error("no heartbeat received within {0:?}")]
+    HeartbeatTimeout(Duration),
+
+    #[error("stream closed by server")]
+    StreamClosed,
+}
+
+impl Error {
+    /// Maps a non-2xx response to its variant.
+    pub fn from_status(status: StatusCode, retry_after: Option<Duration>, error: ApiError) -> Self {
+        let error = Box::new(error);
+        match status {
+            StatusCode::BAD_REQUEST => Error::Validation(error),
+            StatusCode::UNAUTHORIZED => Error::Unauthorized(error),
+            StatusCode::FORBIDDEN => Error::Forbidden(error),
+            StatusCode::NOT_FOUND => Error::NotFound(error),
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited { retry_after, error },
+            status if status.is_server_error() => Error::Server { status, error },
+            status => Error::Status { status, error },
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Unauthorized(_) => Some(StatusCode::UNAUTHORIZED),
+            Error::Forbidden(_) => Some(StatusCode::FORBIDDEN),
+            Error::NotFound(_) => Some(StatusCode::NOT_FOUND),
+            Error::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Error::Validation(_) => Some(StatusCode::BAD_REQUEST),
+            Error::Server { status, .. } | Error::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the request may succeed if sent again: connection
+    /// failures, timeouts, rate limiting and server errors.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Request(error) => error.is_connect() || error.is_timeout(),
+            Error::RateLimited { .. } | Error::Server { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Error::Unauthorized(error)
+            | Error::Forbidden(error)
+            | Error::NotFound(error)
+            | Error::RateLimited { error, .. }
+            | Error::Validation(error)
+            | Error::Server { error, .. }
+            | Error::Status { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The body OANDA returns with a non-2xx response. Order, trade and position
+/// endpoints also embed the transactions that rejected the request, keyed by
+/// their response field, e.g. `takeProfitOrderRejectTransaction`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+    #[serde(flatten)]
+    pub reject_transactions: BTreeMap<String, Transaction>,
+    #[serde(rename = "relatedTransactionIDs")]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID", skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<String>,
+}
+
+impl ApiError {
+    /// Parses a response body, falling back to an empty error when OANDA
+    /// didn't send JSON (e.g. from a proxy).
+    pub fn from_body(body: &[u8]) -> Self {
+        serde_json::from_slice(body)
+            .map(ApiError::from_value)
+            .unwrap_or_default()
+    }
+
+    /// Reads each field on its own so that a reject transaction that fails to
+    /// parse never loses the error code and message.
+    fn from_value(value: Value) -> Self {
+        let Value::Object(fields) = value else {
+            return ApiError::default();
+        };
+        let string = |key: &str| fields.get(key).and_then(Value::as_str).map(str::to_string);
+
+        let reject_transactions: BTreeMap<String, Transaction> = fields
+            .iter()
+            .filter(|(key, _)| key.ends_with("RejectTransaction"))
+            .filter_map(|(key, value)| {
+                let transaction = Transaction::deserialize(value).ok()?;
+                Some((key.clone(), transaction))
+            })
+            .collect();
+
+        let reject_reason = string("rejectReason").or_else(|| {
+            reject_transactions
+                .values()
+                .find_map(Transaction::reject_reason)
+                .map(str::to_string)
+        });
+
+        ApiError {
+            error_code: string("errorCode"),
+            error_message: string("errorMessage"),
+            reject_reason,
+            reject_transactions,
+            related_transaction_ids: fields
+                .get("relatedTransactionIDs")
+                .and_then(|ids| Vec::deserialize(ids).ok())
+                .unwrap_or_default(),
+            last_transaction_id: string("lastTransactionID"),
+        }
+    }
+
+    /// Returns the first embedded reject transaction, which is the only one
+    /// for most endpoints.
+    pub fn reject_transaction(&self) -> Option<&Transaction> {
+        self.reject_transactions.values().next()
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(ApiError::from_value)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = self
+            .error_message
+            .as_deref()
+            .or(self.reject_reason.as_deref())
+            .unwrap_or("no reason given");
+
+        match &self.error_code {
+            Some(code) => write!(f, "{message} ({code})"),
+            None => f.write_str(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::StatusCode;
+
+    use crate::{
+        error::{ApiError, Error},
+        oanda::{order::fixtures::order_rejection_json, Transaction},
+    };
+
+    #[test]
+    fn test_api_error_from_body() {
+        let error = ApiError::from_body(order_rejection_json().as_bytes());
+
+        assert_eq!(
+            Some("PRICE_PRECISION_EXCEEDED"),
+            error.error_code.as_deref()
+        );
+        assert_eq!(
+            Some("PRICE_PRECISION_EXCEEDED"),
+            error.reject_reason.as_deref()
+        );
+        assert!(matches!(
+            error.reject_transaction(),
+            Some(Transaction::LimitOrderReject(_))
+        ));
+        assert_eq!(vec!["6370".to_string()], error.related_transaction_ids);
+        assert_eq!(
+            "The price specifed contains more precision than is allowed (PRICE_PRECISION_EXCEEDED)",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_api_error_from_body_with_many_reject_transactions() {
+        let body = r#"
+        {
+            "takeProfitOrderRejectTransaction": {
+                "type": "TAKE_PROFIT_ORDER_REJECT",
+                "tradeID": "6369",
+                "price": "1.1",
+                "timeInForce": "GTC",
+                "triggerCondition": "DEFAULT",
+                "reason": "CLIENT_ORDER",
+                "rejectReason": "TAKE_PROFIT_ON_FILL_LOSS",
+                "id": "6371",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6371",
+                "time": "2024-01-02T10:00:04.000000000Z"
+            },
+            "stopLossOrderRejectTransaction": "malformed",
+            "relatedTransactionIDs": ["6371", "6372"],
+            "lastTransactionID": "6372",
+            "errorCode": "TAKE_PROFIT_ON_FILL_LOSS",
+            "errorMessage": "The take profit would be a loss"
+        }
+        "#;
+
+        let error = ApiError::from_body(body.as_bytes());
+
+        assert_eq!(
+            Some("TAKE_PROFIT_ON_FILL_LOSS"),
+            error.error_code.as_deref()
+        );
+        assert_eq!(
+            Some("The take profit would be a loss"),
+            error.error_message.as_deref()
+        );
+        assert_eq!(
+            Some("TAKE_PROFIT_ON_FILL_LOSS"),
+            error.reject_reason.as_deref()
+        );
+        assert_eq!(Some("6372"), error.last_transaction_id.as_deref());
+
+        // The malformed stop loss reject is skipped rather than failing the body.
+        assert_eq!(1, error.reject_transactions.len());
+        assert!(matches!(
+            error
+                .reject_transactions
+                .get("takeProfitOrderRejectTransaction"),
+            Some(Transaction::TakeProfitOrderReject(_))
+        ));
+    }
+
+    #[test]
+    fn test_api_error_from_invalid_body() {
+        let error = ApiError::from_body(b"<html>Bad Gateway</html>");
+
+        assert_eq!(ApiError::default(), error);
+        assert_eq!("no reason given", error.to_string());
+    }
+
+    #[test]
+    fn test_error_from_status() {
+        let cases = [
+            (StatusCode::BAD_REQUEST, "validation failed"),
+            (StatusCode::UNAUTHORIZED, "unauthorized"),
+            (StatusCode::FORBIDDEN, "forbidden"),
+            (StatusCode::NOT_FOUND, "not found"),
+            (StatusCode::TOO_MANY_REQUESTS, "rate limited"),
+            (StatusCode::BAD_GATEWAY, "server error (502 Bad Gateway)"),
+            (
+                StatusCode::CONFLICT,
+                "unexpected status code (409 Conflict)",
+            ),
+        ];
+
+        for (status, message) in cases {
+            let error = Error::from_status(status, None, ApiError::default());
+            assert_eq!(Some(status), error.status());
+            assert_eq!(format!("{message}: no reason given"), error.to_string());
+        }
+
+        let error = Error::from_status(
+            StatusCode::TOO_MANY_REQUESTS,
+            Some(Duration::from_secs(2)),
+            ApiError::default(),
+        );
+        assert!(matches!(
+            error,
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            } if retry_after == Duration::from_secs(2)
+        ));
+    }
 }
@@ -1,6 +1,11 @@
 pub(crate) mod deserializer;
+pub(crate) mod macros;
+pub(crate) mod serializer;
 
 pub mod error;
-pub use error::Error;
+pub use error::{ApiError, Error};
+
+pub mod number;
+pub use number::Number;
 
 pub mod oanda;
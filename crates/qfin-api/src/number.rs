@@ -0,0 +1,150 @@
+/// Numeric type used for prices, units and amounts across the OANDA models.
+/// OANDA transmits these as strings; enable the `decimal` feature to parse
+/// them exactly and send them back unchanged. As `f64` they serialize in
+/// their shortest form, so `"100.000"` is sent back as `"100"`.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+/// The largest exponent [`pow10`] supports, which is the decimal backend's
+/// maximum scale.
+pub const MAX_POW10_EXPONENT: i32 = 28;
+
+/// Returns `10^exponent`, with `exponent` clamped to
+/// `-MAX_POW10_EXPONENT..=MAX_POW10_EXPONENT` so both backends agree.
+#[cfg(not(feature = "decimal"))]
+pub fn pow10(exponent: i32) -> Number {
+    10f64.powi(exponent.clamp(-MAX_POW10_EXPONENT, MAX_POW10_EXPONENT))
+}
+
+#[cfg(feature = "decimal")]
+pub fn pow10(exponent: i32) -> Number {
+    let exponent = exponent.clamp(-MAX_POW10_EXPONENT, MAX_POW10_EXPONENT);
+    if exponent < 0 {
+        Number::new(1, exponent.unsigned_abs())
+    } else {
+        Number::from_i128_with_scale(10i128.pow(exponent.unsigned_abs()), 0)
+    }
+}
+
+/// Rounds half away from zero to `places` decimal places.
+#[cfg(not(feature = "decimal"))]
+pub fn round_dp(value: Number, places: u32) -> Number {
+    let factor = pow10(places as i32);
+    (value * factor).round() / factor
+}
+
+#[cfg(feature = "decimal")]
+pub fn round_dp(value: Number, places: u32) -> Number {
+    value.round_dp_with_strategy(places, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+}
+
+/// Truncates towards zero to `places` decimal places.
+#[cfg(not(feature = "decimal"))]
+pub fn trunc_dp(value: Number, places: u32) -> Number {
+    let factor = pow10(places as i32);
+    (value * factor).trunc() / factor
+}
+
+#[cfg(feature = "decimal")]
+pub fn trunc_dp(value: Number, places: u32) -> Number {
+    value.round_dp_with_strategy(places, rust_decimal::RoundingStrategy::ToZero)
+}
+
+#[cfg(test)]
+pub(crate) fn number(value: &str) -> Number {
+    value.parse().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::number::{number, pow10, round_dp, trunc_dp};
+
+    #[test]
+    fn test_pow10() {
+        assert_eq!(number("0.0001"), pow10(-4));
+        assert_eq!(number("1"), pow10(0));
+        assert_eq!(number("100"), pow10(2));
+        assert_eq!(number("10000000000000000000"), pow10(19));
+    }
+
+    #[test]
+    fn test_pow10_clamps_exponent() {
+        assert_eq!(pow10(28), pow10(29));
+        assert_eq!(pow10(28), pow10(i32::MAX));
+        assert_eq!(pow10(-28), pow10(-30));
+        assert_eq!(pow10(-28), pow10(i32::MIN));
+    }
+
+    #[test]
+    fn test_round_dp() {
+        assert_eq!(number("1.08403"), round_dp(number("1.084025"), 5));
+        assert_eq!(number("-1.5"), round_dp(number("-1.45"), 1));
+        assert_eq!(number("3"), round_dp(number("2.5"), 0));
+    }
+
+    #[test]
+    fn test_trunc_dp() {
+        assert_eq!(number("1.0840"), trunc_dp(number("1.08409"), 4));
+        assert_eq!(number("-12"), trunc_dp(number("-12.9"), 0));
+    }
+}
+
+#[cfg(all(test, not(feature = "decimal")))]
+mod float_tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{deserializer::de_string_as_number, serializer::ser_number_as_string, Number};
+
+    #[derive(Deserialize, Serialize)]
+    struct Price {
+        #[serde(
+            deserialize_with = "de_string_as_number",
+            serialize_with = "ser_number_as_string"
+        )]
+        price: Number,
+    }
+
+    #[test]
+    fn test_float_round_trip_drops_trailing_zeros() {
+        let price: Price = serde_json::from_str(r#"{"price":"100.000"}"#).unwrap();
+
+        assert_eq!(100.0, price.price);
+        assert_eq!(r#"{"price":"100"}"#, serde_json::to_string(&price).unwrap());
+
+        let price: Price = serde_json::from_str(r#"{"price":"1.10015"}"#).unwrap();
+        assert_eq!(
+            r#"{"price":"1.10015"}"#,
+            serde_json::to_string(&price).unwrap()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "decimal"))]
+mod decimal_tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        deserializer::de_string_as_number, number::number, serializer::ser_number_as_string, Number,
+    };
+
+    #[derive(Deserialize, Serialize)]
+    struct Price {
+        #[serde(
+            deserialize_with = "de_string_as_number",
+            serialize_with = "ser_number_as_string"
+        )]
+        price: Number,
+    }
+
+    #[test]
+    fn test_decimal_round_trip() {
+        let json = r#"{"price":"1.10000"}"#;
+        let price: Price = serde_json::from_str(json).unwrap();
+
+        assert_eq!(number("1.1"), price.price);
+        assert_eq!(json, serde_json::to_string(&price).unwrap());
+    }
+}
@@ -0,0 +1,596 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    deserializer::{de_option_string_as_number, de_string_as_number},
+    oanda::{Order, Position, TradeSummary, Transaction},
+    serializer::{ser_comma_separated, ser_number_as_string, ser_option_number_as_string},
+    Number,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountProperties {
+    pub id: String,
+    #[serde(rename = "mt4AccountID", skip_serializing_if = "Option::is_none")]
+    pub mt4_account_id: Option<i64>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSummary {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    pub currency: String,
+    #[serde(rename = "createdByUserID")]
+    pub created_by_user_id: i64,
+    pub created_time: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_mode: Option<GuaranteedStopLossOrderMode>,
+    #[serde(rename = "resettablePLTime", skip_serializing_if = "Option::is_none")]
+    pub resettable_pl_time: Option<String>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_rate: Number,
+    pub open_trade_count: i64,
+    pub open_position_count: i64,
+    pub pending_order_count: i64,
+    pub hedging_enabled: bool,
+    #[serde(
+        rename = "unrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub unrealized_pl: Number,
+    #[serde(
+        rename = "NAV",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub nav: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_used: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_available: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub position_value: Number,
+    #[serde(
+        rename = "marginCloseoutUnrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_unrealized_pl: Number,
+    #[serde(
+        rename = "marginCloseoutNAV",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_nav: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_margin_used: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_percent: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_position_value: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub withdrawal_limit: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_call_margin_used: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_call_percent: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub balance: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub pl: Number,
+    #[serde(
+        rename = "resettablePL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub resettable_pl: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub financing: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub commission: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub dividend_adjustment: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub guaranteed_execution_fees: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_call_enter_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_call_extension_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_margin_call_extension_time: Option<DateTime<Utc>>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Account {
+    #[serde(flatten)]
+    pub summary: AccountSummary,
+    #[serde(default)]
+    pub trades: Vec<TradeSummary>,
+    #[serde(default)]
+    pub positions: Vec<Position>,
+    #[serde(default)]
+    pub orders: Vec<Order>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GuaranteedStopLossOrderMode {
+    Disabled,
+    Allowed,
+    Required,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct AccountInstrumentsParams {
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "ser_comma_separated"
+    )]
+    pub instruments: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChanges {
+    #[serde(default)]
+    pub orders_created: Vec<Order>,
+    #[serde(default)]
+    pub orders_cancelled: Vec<Order>,
+    #[serde(default)]
+    pub orders_filled: Vec<Order>,
+    #[serde(default)]
+    pub orders_triggered: Vec<Order>,
+    #[serde(default)]
+    pub trades_opened: Vec<TradeSummary>,
+    #[serde(default)]
+    pub trades_reduced: Vec<TradeSummary>,
+    #[serde(default)]
+    pub trades_closed: Vec<TradeSummary>,
+    #[serde(default)]
+    pub positions: Vec<Position>,
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChangesState {
+    #[serde(
+        rename = "unrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub unrealized_pl: Number,
+    #[serde(
+        rename = "NAV",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub nav: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_used: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_available: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub position_value: Number,
+    #[serde(
+        rename = "marginCloseoutUnrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_unrealized_pl: Number,
+    #[serde(
+        rename = "marginCloseoutNAV",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_nav: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_margin_used: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_percent: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_closeout_position_value: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub withdrawal_limit: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_call_margin_used: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_call_percent: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub balance: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub pl: Option<Number>,
+    #[serde(
+        rename = "resettablePL",
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub resettable_pl: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub financing: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub commission: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub dividend_adjustment: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub guaranteed_execution_fees: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_call_enter_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_call_extension_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_margin_call_extension_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub orders: Vec<DynamicOrderState>,
+    #[serde(default)]
+    pub trades: Vec<CalculatedTradeState>,
+    #[serde(default)]
+    pub positions: Vec<CalculatedPositionState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicOrderState {
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub trailing_stop_value: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub trigger_distance: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_trigger_distance_exact: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculatedTradeState {
+    pub id: String,
+    #[serde(
+        rename = "unrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub unrealized_pl: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_used: Number,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculatedPositionState {
+    pub instrument: String,
+    #[serde(
+        rename = "netUnrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub net_unrealized_pl: Number,
+    #[serde(
+        rename = "longUnrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub long_unrealized_pl: Number,
+    #[serde(
+        rename = "shortUnrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub short_unrealized_pl: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_used: Number,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChangesResponse {
+    pub changes: AccountChanges,
+    pub state: AccountChangesState,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    pub fn accounts_json() -> String {
+        r#"
+        [
+            {
+                "id": "101-004-1234567-001",
+                "tags": []
+            },
+            {
+                "id": "101-004-1234567-002",
+                "mt4AccountID": 1234,
+                "tags": [
+                    "desk-a"
+                ]
+            }
+        ]
+        "#
+        .to_owned()
+    }
+
+    pub fn account_summary_json() -> String {
+        r#"
+        {
+            "id": "101-004-1234567-001",
+            "alias": "Primary",
+            "currency": "USD",
+            "createdByUserID": 1234567,
+            "createdTime": "2023-06-01T12:00:00.000000000Z",
+            "guaranteedStopLossOrderMode": "DISABLED",
+            "resettablePLTime": "0",
+            "marginRate": "0.02",
+            "openTradeCount": 1,
+            "openPositionCount": 1,
+            "pendingOrderCount": 2,
+            "hedgingEnabled": false,
+            "unrealizedPL": "0.0150",
+            "NAV": "99993.1624",
+            "marginUsed": "2.1683",
+            "marginAvailable": "99990.9941",
+            "positionValue": "108.4150",
+            "marginCloseoutUnrealizedPL": "0.0100",
+            "marginCloseoutNAV": "99993.1574",
+            "marginCloseoutMarginUsed": "2.1683",
+            "marginCloseoutPercent": "0.00001",
+            "marginCloseoutPositionValue": "108.4150",
+            "withdrawalLimit": "99990.9941",
+            "marginCallMarginUsed": "2.1683",
+            "marginCallPercent": "0.00002",
+            "balance": "99993.1474",
+            "pl": "-6.8526",
+            "resettablePL": "-6.8526",
+            "financing": "-0.0012",
+            "commission": "0.0000",
+            "dividendAdjustment": "0",
+            "guaranteedExecutionFees": "0.0000",
+            "lastTransactionID": "6383"
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn account_changes_response_json() -> String {
+        r#"
+        {
+            "changes": {
+                "ordersCreated": [],
+                "ordersCancelled": [],
+                "ordersFilled": [
+                    {
+                        "id": "6384",
+                        "createTime": "2024-01-02T13:00:00.000000000Z",
+                        "type": "MARKET",
+                        "instrument": "EUR_USD",
+                        "units": "100",
+                        "timeInForce": "FOK",
+                        "positionFill": "DEFAULT",
+                        "state": "FILLED",
+                        "fillingTransactionID": "6385",
+                        "filledTime": "2024-01-02T13:00:00.000000000Z",
+                        "tradeOpenedID": "6385"
+                    }
+                ],
+                "ordersTriggered": [],
+                "tradesOpened": [
+                    {
+                        "id": "6385",
+                        "instrument": "EUR_USD",
+                        "price": "1.08500",
+                        "openTime": "2024-01-02T13:00:00.000000000Z",
+                        "initialUnits": "100",
+                        "initialMarginRequired": "2.1700",
+                        "state": "OPEN",
+                        "currentUnits": "100",
+                        "realizedPL": "0.0000",
+                        "financing": "0.0000",
+                        "dividendAdjustment": "0.0000"
+                    }
+                ],
+                "tradesReduced": [],
+                "tradesClosed": [],
+                "positions": [],
+                "transactions": [
+                    {
+                        "type": "ORDER_FILL",
+                        "orderID": "6384",
+                        "instrument": "EUR_USD",
+                        "units": "100",
+                        "price": "1.08500",
+                        "reason": "MARKET_ORDER",
+                        "pl": "0.0000",
+                        "financing": "0.0000",
+                        "commission": "0.0000",
+                        "accountBalance": "99993.1474",
+                        "tradeOpened": {
+                            "tradeID": "6385",
+                            "units": "100",
+                            "price": "1.08500"
+                        },
+                        "id": "6385",
+                        "accountID": "101-004-1234567-001",
+                        "userID": 1234567,
+                        "batchID": "6384",
+                        "time": "2024-01-02T13:00:00.000000000Z"
+                    }
+                ]
+            },
+            "state": {
+                "unrealizedPL": "-0.0050",
+                "NAV": "99993.1424",
+                "marginUsed": "4.3383",
+                "marginAvailable": "99988.8041",
+                "positionValue": "216.9150",
+                "marginCloseoutUnrealizedPL": "-0.0100",
+                "marginCloseoutNAV": "99993.1374",
+                "marginCloseoutMarginUsed": "4.3383",
+                "marginCloseoutPercent": "0.00002",
+                "marginCloseoutPositionValue": "216.9150",
+                "withdrawalLimit": "99988.8041",
+                "marginCallMarginUsed": "4.3383",
+                "marginCallPercent": "0.00004",
+                "orders": [
+                    {
+                        "id": "6377",
+                        "trailingStopValue": "1.07902"
+                    }
+                ],
+                "trades": [
+                    {
+                        "id": "6385",
+                        "unrealizedPL": "-0.0050",
+                        "marginUsed": "2.1700"
+                    }
+                ],
+                "positions": [
+                    {
+                        "instrument": "EUR_USD",
+                        "netUnrealizedPL": "-0.0050",
+                        "longUnrealizedPL": "-0.0050",
+                        "shortUnrealizedPL": "0.0000",
+                        "marginUsed": "4.3383"
+                    }
+                ]
+            },
+            "lastTransactionID": "6385"
+        }
+        "#
+        .to_owned()
+    }
+}
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    oanda::{Account, AccountChangesResponse, AccountProperties, AccountSummary, Client},
+    Error,
+};
+
+#[derive(Serialize)]
+struct AccountChangesQuery<'a> {
+    #[serde(rename = "sinceTransactionID")]
+    since_transaction_id: &'a str,
+}
+
+#[derive(Deserialize)]
+struct AccountsData {
+    accounts: Vec<AccountProperties>,
+}
+
+#[derive(Deserialize)]
+struct AccountData<T> {
+    account: T,
+}
+
+pub(super) async fn accounts(client: &Client) -> Result<Vec<AccountProperties>, Error> {
+    let data: AccountsData = client.get("/v3/accounts", &()).await?;
+
+    Ok(data.accounts)
+}
+
+pub(super) async fn account(client: &Client) -> Result<Account, Error> {
+    let path = format!("/v3/accounts/{}", client.account_id);
+    let data: AccountData<Account> = client.get(&path, &()).await?;
+
+    Ok(data.account)
+}
+
+pub(super) async fn account_summary(client: &Client) -> Result<AccountSummary, Error> {
+    let path = format!("/v3/accounts/{}/summary", client.account_id);
+    let data: AccountData<AccountSummary> = client.get(&path, &()).await?;
+
+    Ok(data.account)
+}
+
+pub(super) async fn account_changes(
+    client: &Client,
+    since_transaction_id: &str,
+) -> Result<AccountChangesResponse, Error> {
+    let path = format!("/v3/accounts/{}/changes", client.account_id);
+    let query = AccountChangesQuery {
+        since_transaction_id,
+    };
+
+    client.get(&path, &query).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::{
+        number::number,
+        oanda::{
+            account::fixtures::{
+                account_changes_response_json, account_summary_json, accounts_json,
+            },
+            order::fixtures::orders_json,
+            position::fixtures::positions_json,
+            Api, Client, GuaranteedStopLossOrderMode, Order, Transaction, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    #[tokio::test]
+    async fn test_accounts() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock("GET", "/v3/accounts")
+            .with_status(200)
+            .with_body(format!(r#"{{"accounts": {}}}"#, accounts_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let accounts = client.accounts().await.unwrap();
+
+        assert_eq!(2, accounts.len());
+        assert_eq!(None, accounts[0].mt4_account_id);
+        assert_eq!(Some(1234), accounts[1].mt4_account_id);
+        assert_eq!(vec!["desk-a".to_string()], accounts[1].tags);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_account() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mut account: serde_json::Value = serde_json::from_str(&account_summary_json()).unwrap();
+        account["orders"] = serde_json::from_str(&orders_json()).unwrap();
+        account["positions"] = serde_json::from_str(&positions_json()).unwrap();
+        account["trades"] = serde_json::json!([]);
+
+        let mock = server
+            .mock("GET", format!("/v3/accounts/{}", ACCOUNT_ID).as_str())
+            .with_status(200)
+            .with_body(
+                serde_json::json!({ "account": account, "lastTransactionID": "6383" }).to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let account = client.account().await.unwrap();
+
+        assert_eq!(number("99993.1624"), account.summary.nav);
+        assert_eq!(2, account.orders.len());
+        assert_eq!(1, account.positions.len());
+        assert!(account.trades.is_empty());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_account_summary() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/summary", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"account": {}, "lastTransactionID": "6383"}}"#,
+                account_summary_json()
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let summary = client.account_summary().await.unwrap();
+
+        assert_eq!("USD", summary.currency);
+        assert_eq!(number("99990.9941"), summary.margin_available);
+        assert_eq!(number("0.00001"), summary.margin_closeout_percent);
+        assert_eq!(1, summary.open_trade_count);
+        assert_eq!(
+            Some(GuaranteedStopLossOrderMode::Disabled),
+            summary.guaranteed_stop_loss_order_mode
+        );
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_account_changes() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/changes", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::UrlEncoded(
+                "sinceTransactionID".into(),
+                "6383".into(),
+            ))
+            .with_status(200)
+            .with_body(account_changes_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let resp = client.account_changes("6383").await.unwrap();
+
+        assert_eq!("6385", resp.last_transaction_id);
+        assert!(matches!(resp.changes.orders_filled[0], Order::Market(_)));
+        assert_eq!(1, resp.changes.trades_opened.len());
+        assert!(matches!(
+            resp.changes.transactions[0],
+            Transaction::OrderFill(_)
+        ));
+        assert_eq!(number("4.3383"), resp.state.margin_used);
+        assert_eq!(
+            Some(number("1.07902")),
+            resp.state.orders[0].trailing_stop_value
+        );
+        assert_eq!(number("-0.005"), resp.state.positions[0].net_unrealized_pl);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_account_unauthorized_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock("GET", format!("/v3/accounts/{}", ACCOUNT_ID).as_str())
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let err = client.account().await;
+        assert!(err.is_err_and(|err| matches!(err, Error::Unauthorized(_))))
+    }
+}
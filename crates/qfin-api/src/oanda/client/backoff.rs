@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: f64,
+    pub max_retries: Option<u32>,
+}
+
+impl Backoff {
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exp = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let secs = self.initial.as_secs_f64() * self.multiplier.powi(exp);
+        if secs.is_finite() && secs < self.max.as_secs_f64() {
+            Duration::from_secs_f64(secs)
+        } else {
+            self.max
+        }
+    }
+
+    pub fn exhausted(&self, attempt: u32) -> bool {
+        self.max_retries.is_some_and(|max| attempt >= max)
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(60),
+            multiplier: 2.0,
+            max_retries: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::oanda::client::Backoff;
+
+    #[test]
+    fn test_backoff_delay() {
+        let backoff = Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+            multiplier: 2.0,
+            max_retries: None,
+        };
+
+        assert_eq!(Duration::from_millis(100), backoff.delay(0));
+        assert_eq!(Duration::from_millis(200), backoff.delay(1));
+        assert_eq!(Duration::from_millis(800), backoff.delay(3));
+        assert_eq!(Duration::from_secs(1), backoff.delay(4));
+        assert_eq!(Duration::from_secs(1), backoff.delay(u32::MAX));
+    }
+
+    #[test]
+    fn test_backoff_exhausted() {
+        let backoff = Backoff {
+            max_retries: Some(2),
+            ..Default::default()
+        };
+
+        assert!(!backoff.exhausted(1));
+        assert!(backoff.exhausted(2));
+        assert!(!Backoff::default().exhausted(u32::MAX));
+    }
+}
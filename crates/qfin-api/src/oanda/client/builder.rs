@@ -0,0 +1,450 @@
+use std::{env, fmt, str::FromStr, sync::Arc, time::Duration};
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Certificate, Proxy,
+};
+
+use crate::{
+    oanda::{
+        client::{RateLimitPolicy, RateLimiters, RetryPolicy},
+        Client, Url,
+    },
+    Error,
+};
+
+pub const ACCOUNT_ID_VAR: &str = "OANDA_ACCOUNT_ID";
+pub const TOKEN_VAR: &str = "OANDA_TOKEN";
+pub const ENV_VAR: &str = "OANDA_ENV";
+
+/// The format OANDA uses for timestamps in responses.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DatetimeFormat {
+    #[default]
+    Rfc3339,
+    Unix,
+}
+
+impl DatetimeFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DatetimeFormat::Rfc3339 => "RFC3339",
+            DatetimeFormat::Unix => "UNIX",
+        }
+    }
+}
+
+impl FromStr for DatetimeFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "RFC3339" => Ok(DatetimeFormat::Rfc3339),
+            "UNIX" => Ok(DatetimeFormat::Unix),
+            s => Err(Error::Config(format!("invalid datetime format: {s}"))),
+        }
+    }
+}
+
+/// Builds a [`Client`] along with the underlying `reqwest::Client`.
+///
+/// There is no overall request timeout since it would also end long-lived
+/// streams; the read timeout bounds the time between chunks instead.
+pub struct ClientBuilder {
+    account_id: Option<String>,
+    auth_token: Option<String>,
+    url: Url,
+    stream_url: Option<String>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    user_agent: String,
+    datetime_format: DatetimeFormat,
+    proxy: Option<Proxy>,
+    root_certificates: Vec<Certificate>,
+    accept_invalid_certs: bool,
+    retry_policy: Option<RetryPolicy>,
+    rate_limit_policy: Option<RateLimitPolicy>,
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        ClientBuilder {
+            account_id: None,
+            auth_token: None,
+            url: Url::Practice,
+            stream_url: None,
+            connect_timeout: Some(Duration::from_secs(10)),
+            read_timeout: Some(Duration::from_secs(30)),
+            user_agent: concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_string(),
+            datetime_format: DatetimeFormat::default(),
+            proxy: None,
+            root_certificates: Vec::new(),
+            accept_invalid_certs: false,
+            retry_policy: None,
+            rate_limit_policy: Some(RateLimitPolicy::default()),
+        }
+    }
+
+    /// Reads the account id from `OANDA_ACCOUNT_ID`, the token from
+    /// `OANDA_TOKEN` and the environment from `OANDA_ENV`, which is `live`,
+    /// `practice` (the default) or a custom URL.
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, Error> {
+        let required = |name| var(name).ok_or_else(|| Error::Config(format!("{name} is not set")));
+
+        let builder = ClientBuilder::new()
+            .account_id(&required(ACCOUNT_ID_VAR)?)
+            .auth_token(&required(TOKEN_VAR)?);
+
+        match var(ENV_VAR) {
+            Some(env) => Ok(builder.url(env.parse()?)),
+            None => Ok(builder),
+        }
+    }
+
+    pub fn account_id(mut self, account_id: &str) -> Self {
+        self.account_id = Some(account_id.to_string());
+        self
+    }
+
+    pub fn auth_token(mut self, auth_token: &str) -> Self {
+        self.auth_token = Some(auth_token.to_string());
+        self
+    }
+
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = url;
+        self
+    }
+
+    /// Sends stream requests to `stream_url` while other requests keep using
+    /// the URL set with [`ClientBuilder::url`].
+    pub fn stream_url(mut self, stream_url: &str) -> Self {
+        self.stream_url = Some(stream_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Sets the `Accept-Datetime-Format` header. The models only parse RFC
+    /// 3339 timestamps, so `Unix` is only useful with a custom `Url` that
+    /// rewrites them.
+    pub fn datetime_format(mut self, format: DatetimeFormat) -> Self {
+        self.datetime_format = format;
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Skips TLS certificate verification. Only use this against a local
+    /// proxy or mock server.
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Replaces the default [`RateLimitPolicy`].
+    pub fn rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limit_policy = Some(policy);
+        self
+    }
+
+    pub fn without_rate_limit(mut self) -> Self {
+        self.rate_limit_policy = None;
+        self
+    }
+
+    pub fn build(self) -> Result<Client, Error> {
+        let url = self.resolved_url();
+        let account_id = self
+            .account_id
+            .ok_or_else(|| Error::Config("account id is not set".to_string()))?;
+        let auth_token = self
+            .auth_token
+            .ok_or_else(|| Error::Config("auth token is not set".to_string()))?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Accept-Datetime-Format",
+            HeaderValue::from_static(self.datetime_format.as_str()),
+        );
+
+        let mut builder = reqwest::Client::builder()
+            .user_agent(self.user_agent)
+            .default_headers(headers)
+            .danger_accept_invalid_certs(self.accept_invalid_certs);
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        for certificate in self.root_certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+
+        let client = builder.build().map_err(Error::Request)?;
+
+        Ok(Client {
+            retry_policy: self.retry_policy,
+            rate_limiters: self
+                .rate_limit_policy
+                .map(|policy| Arc::new(RateLimiters::new(policy))),
+            ..Client::new(client, &account_id, &auth_token, url)
+        })
+    }
+
+    pub(crate) fn resolved_url(&self) -> Url {
+        match &self.stream_url {
+            Some(stream) => Url::Hosts {
+                api: self.url.to_string(),
+                stream: stream.clone(),
+            },
+            None => self.url.clone(),
+        }
+    }
+}
+
+// The auth token is a bearer credential, so it is never printed.
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("account_id", &self.account_id)
+            .field(
+                "auth_token",
+                &self.auth_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("url", &self.url)
+            .field("stream_url", &self.stream_url)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("read_timeout", &self.read_timeout)
+            .field("user_agent", &self.user_agent)
+            .field("datetime_format", &self.datetime_format)
+            .field("proxy", &self.proxy)
+            .field("root_certificates", &self.root_certificates)
+            .field("accept_invalid_certs", &self.accept_invalid_certs)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limit_policy", &self.rate_limit_policy)
+            .finish()
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        ClientBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use reqwest::header::{AUTHORIZATION, USER_AGENT};
+
+    use crate::{
+        oanda::{
+            client::{ClientBuilder, DatetimeFormat},
+            instrument::fixtures::instruments_json,
+            Api, Client, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    fn vars(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[tokio::test]
+    async fn test_client_builder() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/instruments", ACCOUNT_ID).as_str(),
+            )
+            .match_header(AUTHORIZATION, format!("Bearer {}", AUTH_TOKEN).as_str())
+            .match_header(USER_AGENT, "my-bot/1.0")
+            .match_header("Accept-Datetime-Format", "UNIX")
+            .with_status(200)
+            .with_body(format!(r#"{{"instruments": {}}}"#, instruments_json()))
+            .create_async()
+            .await;
+
+        let client = Client::builder()
+            .account_id(ACCOUNT_ID)
+            .auth_token(AUTH_TOKEN)
+            .url(Url::Custom(url))
+            .user_agent("my-bot/1.0")
+            .datetime_format(DatetimeFormat::Unix)
+            .build()
+            .unwrap();
+
+        assert!(client.instruments().await.is_ok());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_client_builder_default_headers() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/instruments", ACCOUNT_ID).as_str(),
+            )
+            .match_header(USER_AGENT, concat!("qfin-api/", env!("CARGO_PKG_VERSION")))
+            .match_header("Accept-Datetime-Format", "RFC3339")
+            .with_status(200)
+            .with_body(format!(r#"{{"instruments": {}}}"#, instruments_json()))
+            .create_async()
+            .await;
+
+        let client = ClientBuilder::new()
+            .account_id(ACCOUNT_ID)
+            .auth_token(AUTH_TOKEN)
+            .url(Url::Custom(url))
+            .build()
+            .unwrap();
+
+        assert!(client.instruments().await.is_ok());
+
+        mock.assert();
+    }
+
+    #[test]
+    fn test_client_builder_stream_url() {
+        let want = Url::Hosts {
+            api: "http://localhost:8080".to_string(),
+            stream: "http://localhost:8081".to_string(),
+        };
+
+        let builder = ClientBuilder::new()
+            .url(Url::Custom("http://localhost:8080".to_string()))
+            .stream_url("http://localhost:8081/");
+        assert_eq!(want, builder.resolved_url());
+
+        let builder = ClientBuilder::new()
+            .stream_url("http://localhost:8081/")
+            .url(Url::Custom("http://localhost:8080".to_string()));
+        assert_eq!(want, builder.resolved_url());
+
+        let builder = ClientBuilder::new()
+            .url(Url::Live)
+            .stream_url("http://localhost:8081");
+        assert_eq!(
+            Url::Hosts {
+                api: "https://api-fxtrade.oanda.com".to_string(),
+                stream: "http://localhost:8081".to_string(),
+            },
+            builder.resolved_url()
+        );
+    }
+
+    #[test]
+    fn test_client_builder_debug_redacts_auth_token() {
+        let builder = ClientBuilder::new()
+            .account_id(ACCOUNT_ID)
+            .auth_token("secret-token");
+
+        let debug = format!("{builder:?}");
+        assert!(debug.contains(ACCOUNT_ID));
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("secret-token"));
+    }
+
+    #[test]
+    fn test_client_builder_missing_config() {
+        let err = ClientBuilder::new().auth_token(AUTH_TOKEN).build();
+        assert!(err.is_err_and(|err| matches!(err, Error::Config(_))));
+
+        let err = ClientBuilder::new().account_id(ACCOUNT_ID).build();
+        assert!(err.is_err_and(|err| matches!(err, Error::Config(_))));
+    }
+
+    #[test]
+    fn test_client_builder_from_vars() {
+        let builder = ClientBuilder::from_vars(vars(&[
+            ("OANDA_ACCOUNT_ID", ACCOUNT_ID),
+            ("OANDA_TOKEN", AUTH_TOKEN),
+            ("OANDA_ENV", "live"),
+        ]))
+        .unwrap();
+        assert_eq!(Some(ACCOUNT_ID), builder.account_id.as_deref());
+        assert_eq!(Some(AUTH_TOKEN), builder.auth_token.as_deref());
+        assert_eq!(Url::Live, builder.url);
+
+        let builder = ClientBuilder::from_vars(vars(&[
+            ("OANDA_ACCOUNT_ID", ACCOUNT_ID),
+            ("OANDA_TOKEN", AUTH_TOKEN),
+        ]))
+        .unwrap();
+        assert_eq!(Url::Practice, builder.url);
+
+        let builder = ClientBuilder::from_vars(vars(&[
+            ("OANDA_ACCOUNT_ID", ACCOUNT_ID),
+            ("OANDA_TOKEN", AUTH_TOKEN),
+            ("OANDA_ENV", "http://localhost:8080"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            Url::Custom("http://localhost:8080".to_string()),
+            builder.url
+        );
+    }
+
+    #[test]
+    fn test_client_builder_from_vars_error() {
+        let err = ClientBuilder::from_vars(vars(&[("OANDA_ACCOUNT_ID", ACCOUNT_ID)]));
+        assert!(err.is_err_and(|err| err.to_string().contains("OANDA_TOKEN")));
+
+        let err = ClientBuilder::from_vars(vars(&[
+            ("OANDA_ACCOUNT_ID", ACCOUNT_ID),
+            ("OANDA_TOKEN", AUTH_TOKEN),
+            ("OANDA_ENV", "staging"),
+        ]));
+        assert!(err.is_err_and(|err| matches!(err, Error::Config(_))));
+    }
+}
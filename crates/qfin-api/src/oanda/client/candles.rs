@@ -0,0 +1,245 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{
+    oanda::{Api, CandlesParams, Candlestick, CandlestickGranularity, Client},
+    Error,
+};
+
+pub const MAX_CANDLES_PER_REQUEST: i32 = 5000;
+
+impl Client {
+    /// Fetches every candle between `from` and `to`, splitting the range into
+    /// windows that stay under OANDA's per-request candle limit. Up to
+    /// `concurrency` windows are requested at once.
+    ///
+    /// Fails with [`Error::Params`] for an unknown granularity, since its
+    /// candle length isn't known and the range can't be split.
+    pub async fn instrument_candles_range(
+        &self,
+        instrument: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        params: &CandlesParams,
+        concurrency: usize,
+    ) -> Result<Vec<Candlestick>, Error> {
+        let granularity = params
+            .granularity
+            .clone()
+            .unwrap_or(CandlestickGranularity::S5);
+        let step = granularity_step(&granularity).ok_or_else(|| {
+            Error::Params(format!("unknown candlestick granularity: {granularity}"))
+        })?;
+        let windows = windows(from, to, step).into_iter().map(|(from, to)| {
+            let mut params = params.clone();
+            params.granularity = Some(granularity.clone());
+            params.from = Some(from);
+            params.to = Some(to);
+            params.count = None;
+            params
+        });
+
+        let pages: Vec<Vec<Candlestick>> = futures::stream::iter(windows)
+            .map(|params| async move { self.instrument_candles(instrument, &params).await })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        let mut candles: Vec<Candlestick> = pages.into_iter().flatten().collect();
+        candles.sort_by_key(|candle| candle.time);
+        candles.dedup_by_key(|candle| candle.time);
+
+        Ok(candles)
+    }
+}
+
+fn windows(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    step: TimeDelta,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    // Both ends of a window may be returned by OANDA, so windows are kept one
+    // candle short of the limit and the shared boundary is de-duplicated.
+    let span = step * (MAX_CANDLES_PER_REQUEST - 1);
+
+    let mut windows = Vec::new();
+    let mut start = from;
+    while start < to {
+        let end = (start + span).min(to);
+        windows.push((start, end));
+        start = end;
+    }
+
+    windows
+}
+
+fn granularity_step(granularity: &CandlestickGranularity) -> Option<TimeDelta> {
+    let step = match granularity {
+        CandlestickGranularity::S5 => TimeDelta::seconds(5),
+        CandlestickGranularity::S10 => TimeDelta::seconds(10),
+        CandlestickGranularity::S15 => TimeDelta::seconds(15),
+        CandlestickGranularity::S30 => TimeDelta::seconds(30),
+        CandlestickGranularity::M1 => TimeDelta::minutes(1),
+        CandlestickGranularity::M2 => TimeDelta::minutes(2),
+        CandlestickGranularity::M4 => TimeDelta::minutes(4),
+        CandlestickGranularity::M5 => TimeDelta::minutes(5),
+        CandlestickGranularity::M10 => TimeDelta::minutes(10),
+        CandlestickGranularity::M15 => TimeDelta::minutes(15),
+        CandlestickGranularity::M30 => TimeDelta::minutes(30),
+        CandlestickGranularity::H1 => TimeDelta::hours(1),
+        CandlestickGranularity::H2 => TimeDelta::hours(2),
+        CandlestickGranularity::H3 => TimeDelta::hours(3),
+        CandlestickGranularity::H4 => TimeDelta::hours(4),
+        CandlestickGranularity::H6 => TimeDelta::hours(6),
+        CandlestickGranularity::H8 => TimeDelta::hours(8),
+        CandlestickGranularity::H12 => TimeDelta::hours(12),
+        CandlestickGranularity::D => TimeDelta::days(1),
+        CandlestickGranularity::W => TimeDelta::weeks(1),
+        // Shortest month, so a window never spans more than the limit.
+        CandlestickGranularity::M => TimeDelta::days(28),
+        CandlestickGranularity::Unknown(_) => return None,
+    };
+
+    Some(step)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, SecondsFormat, TimeDelta, TimeZone, Utc};
+    use mockito::Matcher;
+
+    use crate::{
+        oanda::{
+            client::candles::{windows, MAX_CANDLES_PER_REQUEST},
+            CandlesParams, CandlestickGranularity, Client, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    fn candle_json(time: DateTime<Utc>) -> String {
+        format!(
+            r#"{{"complete": true, "mid": {{"o": "1.1", "h": "1.2", "l": "1.0", "c": "1.1"}}, "time": "{}", "volume": 10}}"#,
+            time.to_rfc3339_opts(SecondsFormat::Secs, true)
+        )
+    }
+
+    #[test]
+    fn test_windows() {
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let span = TimeDelta::minutes(1) * (MAX_CANDLES_PER_REQUEST - 1);
+        let to = from + span * 2 + TimeDelta::minutes(10);
+
+        let windows = windows(from, to, TimeDelta::minutes(1));
+
+        assert_eq!(
+            vec![
+                (from, from + span),
+                (from + span, from + span * 2),
+                (from + span * 2, to)
+            ],
+            windows
+        );
+    }
+
+    #[test]
+    fn test_windows_empty_range() {
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        assert!(windows(from, from, TimeDelta::minutes(1)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_instrument_candles_range() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let boundary = from + TimeDelta::seconds(5) * (MAX_CANDLES_PER_REQUEST - 1);
+        let to = boundary + TimeDelta::minutes(1);
+
+        let first = server
+            .mock("GET", "/v3/instruments/EUR_USD/candles")
+            .match_query(Matcher::UrlEncoded(
+                "from".into(),
+                from.to_rfc3339_opts(SecondsFormat::Secs, true),
+            ))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"candles": [{}, {}]}}"#,
+                candle_json(from),
+                candle_json(boundary)
+            ))
+            .create_async()
+            .await;
+
+        let second = server
+            .mock("GET", "/v3/instruments/EUR_USD/candles")
+            .match_query(Matcher::UrlEncoded(
+                "from".into(),
+                boundary.to_rfc3339_opts(SecondsFormat::Secs, true),
+            ))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"candles": [{}, {}]}}"#,
+                candle_json(boundary),
+                candle_json(to)
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let candles = client
+            .instrument_candles_range("EUR_USD", from, to, &CandlesParams::default(), 2)
+            .await
+            .unwrap();
+
+        let times: Vec<_> = candles.iter().map(|candle| candle.time).collect();
+        assert_eq!(vec![from, boundary, to], times);
+
+        first.assert();
+        second.assert();
+    }
+
+    #[tokio::test]
+    async fn test_instrument_candles_range_unknown_granularity() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock("GET", "/v3/instruments/EUR_USD/candles")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let params = CandlesParams {
+            granularity: Some(CandlestickGranularity::Unknown("M3".to_string())),
+            ..Default::default()
+        };
+
+        let err = client
+            .instrument_candles_range("EUR_USD", from, from + TimeDelta::days(365), &params, 2)
+            .await;
+
+        assert!(err.is_err_and(|err| matches!(err, Error::Params(_))));
+
+        mock.assert();
+    }
+}
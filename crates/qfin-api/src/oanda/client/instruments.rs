@@ -1,56 +1,102 @@
-use async_trait::async_trait;
-use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    oanda::{Api, Client, Instrument},
+    oanda::{
+        AccountInstrumentsParams, CandlesParams, Candlestick, Client, Instrument, OrderBook,
+        PositionBook,
+    },
     Error,
 };
 
+#[derive(Serialize)]
+struct BookQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time: Option<DateTime<Utc>>,
+}
+
 #[derive(Deserialize)]
 struct InstrumentsData {
     instruments: Vec<Instrument>,
 }
 
-#[async_trait]
-impl Api for Client {
-    async fn instruments(&self) -> Result<Vec<Instrument>, Error> {
-        let url = format!(
-            "{}/v3/accounts/{}/instruments",
-            self.url.to_string(),
-            self.account_id
-        );
+#[derive(Deserialize)]
+struct CandlesData {
+    candles: Vec<Candlestick>,
+}
 
-        let resp = self
-            .client
-            .get(url)
-            .header(AUTHORIZATION, format!("Bearer {}", self.auth_token))
-            .header(CONTENT_TYPE, "application/json")
-            .send()
-            .await
-            .map_err(|err| Error::Request(err))?;
-
-        if resp.status() != reqwest::StatusCode::OK {
-            return Err(Error::StatusNotOK(resp.status()));
-        }
-
-        let data = resp
-            .json::<InstrumentsData>()
-            .await
-            .map_err(|err| Error::Deserialize(err))?;
-
-        Ok(data.instruments)
-    }
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OrderBookData {
+    order_book: OrderBook,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PositionBookData {
+    position_book: PositionBook,
+}
+
+pub(super) async fn instruments(
+    client: &Client,
+    params: &AccountInstrumentsParams,
+) -> Result<Vec<Instrument>, Error> {
+    let path = format!("/v3/accounts/{}/instruments", client.account_id);
+    let data: InstrumentsData = client.get(&path, params).await?;
+
+    Ok(data.instruments)
+}
+
+pub(super) async fn instrument_candles(
+    client: &Client,
+    instrument: &str,
+    params: &CandlesParams,
+) -> Result<Vec<Candlestick>, Error> {
+    let path = format!("/v3/instruments/{}/candles", instrument);
+    let data: CandlesData = client.get(&path, params).await?;
+
+    Ok(data.candles)
+}
+
+pub(super) async fn order_book(
+    client: &Client,
+    instrument: &str,
+    time: Option<DateTime<Utc>>,
+) -> Result<OrderBook, Error> {
+    let path = format!("/v3/instruments/{}/orderBook", instrument);
+    let data: OrderBookData = client.get(&path, &BookQuery { time }).await?;
+
+    Ok(data.order_book)
+}
+
+pub(super) async fn position_book(
+    client: &Client,
+    instrument: &str,
+    time: Option<DateTime<Utc>>,
+) -> Result<PositionBook, Error> {
+    let path = format!("/v3/instruments/{}/positionBook", instrument);
+    let data: PositionBookData = client.get(&path, &BookQuery { time }).await?;
+
+    Ok(data.position_book)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+    use mockito::Matcher;
     use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
 
     use crate::{
+        number::number,
         oanda::{
-            fixtures::{instruments, instruments_json},
-            Api, Client, Url,
+            instrument::fixtures::{
+                candles, candles_json, instruments, instruments_json, order_book_json,
+                position_book_json,
+            },
+            AccountInstrumentsParams, Api, CandlesParams, CandlestickGranularity, Client,
+            PriceComponent, Url, WeeklyAlignment,
         },
         Error,
     };
@@ -91,7 +137,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_instruments_status_not_ok_error() {
+    async fn test_instruments_server_error() {
         let mut server = mockito::Server::new_async().await;
         let url = server.url();
 
@@ -115,7 +161,44 @@ mod tests {
         let err = client.instruments().await;
         assert!(err.is_err_and(|err| matches!(
             err,
-            Error::StatusNotOK(reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+            Error::Server {
+                status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                ..
+            }
+        )))
+    }
+
+    #[tokio::test]
+    async fn test_instruments_rate_limited_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/instruments", ACCOUNT_ID).as_str(),
+            )
+            .with_status(429)
+            .with_header("Retry-After", "2")
+            .with_body(r#"{"errorMessage": "Requests per second exceeded"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let err = client.instruments().await;
+        assert!(err.is_err_and(|err| matches!(
+            err,
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+                error,
+            } if retry_after == Duration::from_secs(2)
+                && error.error_message.as_deref() == Some("Requests per second exceeded")
         )))
     }
 
@@ -144,4 +227,187 @@ mod tests {
         let err = client.instruments().await;
         assert!(err.is_err_and(|err| matches!(err, Error::Deserialize(_),)))
     }
+
+    #[tokio::test]
+    async fn test_account_instruments() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/instruments", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::UrlEncoded(
+                "instruments".into(),
+                "EUR_USD,USD_JPY".into(),
+            ))
+            .with_status(200)
+            .with_body(format!(r#"{{"instruments": {}}}"#, instruments_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = AccountInstrumentsParams {
+            instruments: vec!["EUR_USD".to_string(), "USD_JPY".to_string()],
+        };
+
+        assert!(client.account_instruments(&params).await.is_ok());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_instrument_candles() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock("GET", "/v3/instruments/EUR_USD/candles")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("price".into(), "MBA".into()),
+                Matcher::UrlEncoded("granularity".into(), "M5".into()),
+                Matcher::UrlEncoded("from".into(), "2024-01-02T10:00:00Z".into()),
+                Matcher::UrlEncoded("count".into(), "3".into()),
+            ]))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"instrument": "EUR_USD", "granularity": "M5", "candles": {}}}"#,
+                candles_json()
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = CandlesParams {
+            price: vec![
+                PriceComponent::Mid,
+                PriceComponent::Bid,
+                PriceComponent::Ask,
+            ],
+            granularity: Some(CandlestickGranularity::M5),
+            from: Some(Utc.with_ymd_and_hms(2024, 1, 2, 10, 0, 0).unwrap()),
+            count: Some(3),
+            ..Default::default()
+        };
+
+        let results = client.instrument_candles("EUR_USD", &params).await.unwrap();
+        let candles = candles();
+
+        assert_eq!(candles.len(), results.len());
+        assert_eq!(candles[0].time, results[0].time);
+        assert!(!results[2].complete);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_instrument_candles_validation_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock("GET", "/v3/instruments/EUR_USD/candles")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("granularity".into(), "M3".into()),
+                Matcher::UrlEncoded("weeklyAlignment".into(), "Someday".into()),
+            ]))
+            .with_status(400)
+            .with_body(r#"{"errorMessage": "Invalid value specified for 'granularity'"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = CandlesParams {
+            granularity: Some(CandlestickGranularity::Unknown("M3".to_string())),
+            weekly_alignment: Some(WeeklyAlignment::Unknown("Someday".to_string())),
+            ..Default::default()
+        };
+
+        let err = client.instrument_candles("EUR_USD", &params).await;
+        assert!(err.is_err_and(|err| matches!(err, Error::Validation(_))));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_order_book() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock("GET", "/v3/instruments/EUR_USD/orderBook")
+            .match_query(Matcher::UrlEncoded(
+                "time".into(),
+                "2024-01-02T10:00:00Z".into(),
+            ))
+            .with_status(200)
+            .with_body(format!(r#"{{"orderBook": {}}}"#, order_book_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let time = Utc.with_ymd_and_hms(2024, 1, 2, 10, 0, 0).unwrap();
+        let book = client.order_book("EUR_USD", Some(time)).await.unwrap();
+
+        assert_eq!(time, book.time);
+        assert_eq!(number("0.0005"), book.bucket_width);
+        assert_eq!(2, book.buckets.len());
+        assert_eq!(number("0.3215"), book.buckets[0].long_count_percent);
+        assert_eq!(number("0.4055"), book.buckets[1].short_count_percent);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_position_book() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock("GET", "/v3/instruments/EUR_USD/positionBook")
+            .match_query(Matcher::Missing)
+            .with_status(200)
+            .with_body(format!(r#"{{"positionBook": {}}}"#, position_book_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let book = client.position_book("EUR_USD", None).await.unwrap();
+
+        assert_eq!(1, book.buckets.len());
+        assert_eq!(number("0.512"), book.buckets[0].long_count_percent);
+
+        mock.assert();
+    }
 }
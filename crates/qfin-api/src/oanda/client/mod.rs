@@ -1,12 +1,59 @@
-use crate::oanda::Url;
+use std::{fmt, sync::Arc, time::Duration};
 
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{
+    header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER},
+    RequestBuilder, Response,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    oanda::{
+        Account, AccountChangesResponse, AccountInstrumentsParams, AccountProperties,
+        AccountSummary, Api, CancelOrderResponse, CandlesParams, Candlestick, ClientExtensions,
+        ClosePositionRequest, ClosePositionResponse, CloseTradeResponse, CloseTradeUnits,
+        CreateOrderResponse, Instrument, Order, OrderBook, OrderRequest, OrderSpecifier,
+        OrdersParams, Position, PositionBook, Pricing, PricingParams, ReplaceOrderResponse,
+        SetOrderClientExtensionsResponse, SetTradeDependentOrdersResponse, Trade,
+        TradeDependentOrders, TradeSpecifier, TradesParams, Transaction, TransactionPages,
+        TransactionsIdRangeParams, TransactionsParams, TransactionsSinceIdParams, Url,
+    },
+    ApiError, Error,
+};
+
+pub mod backoff;
+pub use backoff::Backoff;
+
+pub mod builder;
+pub use builder::{ClientBuilder, DatetimeFormat};
+
+pub mod rate_limit;
+use rate_limit::RateLimiters;
+pub use rate_limit::{RateLimit, RateLimitPolicy, RateLimiter};
+
+pub mod retry;
+pub use retry::{RetryEvent, RetryHook, RetryPolicy};
+
+pub mod accounts;
+pub mod candles;
 pub mod instruments;
+pub mod orders;
+pub mod positions;
+pub mod pricing;
+pub mod trades;
+pub mod transactions;
 
+mod stream;
+
+#[derive(Clone)]
 pub struct Client {
     client: reqwest::Client,
     account_id: String,
     auth_token: String,
     url: Url,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiters: Option<Arc<RateLimiters>>,
 }
 
 impl Client {
@@ -16,6 +63,354 @@ impl Client {
             account_id: account_id.to_string(),
             auth_token: auth_token.to_string(),
             url,
+            retry_policy: None,
+            rate_limiters: Some(Arc::new(RateLimiters::new(RateLimitPolicy::default()))),
+        }
+    }
+
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Builds a client from the `OANDA_ACCOUNT_ID`, `OANDA_TOKEN` and
+    /// `OANDA_ENV` environment variables. See [`ClientBuilder::from_env`].
+    pub fn from_env() -> Result<Self, Error> {
+        ClientBuilder::from_env()?.build()
+    }
+
+    /// Retries transient failures according to `policy`. Without a policy
+    /// every request is sent once.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Queues requests so they stay within `policy`. The limits are shared
+    /// with every clone of the returned client. New clients use
+    /// [`RateLimitPolicy::default`].
+    pub fn with_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limiters = Some(Arc::new(RateLimiters::new(policy)));
+        self
+    }
+
+    /// Sends every request immediately, e.g. when limits are enforced by a
+    /// proxy in front of OANDA.
+    pub fn without_rate_limit(mut self) -> Self {
+        self.rate_limiters = None;
+        self
+    }
+
+    async fn get<Q, T>(&self, path: &str, query: &Q) -> Result<T, Error>
+    where
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let url = format!("{}{}", self.url, path);
+        let resp = self
+            .send_with_retry(self.client.get(url).query(query), true, None)
+            .await?;
+
+        resp.json::<T>().await.map_err(Error::Deserialize)
+    }
+
+    async fn get_stream<Q>(&self, path: &str, query: &Q) -> Result<Response, Error>
+    where
+        Q: Serialize + ?Sized,
+    {
+        let url = format!("{}{}", self.url.stream_url(), path);
+        if let Some(rate_limiters) = &self.rate_limiters {
+            rate_limiters.stream.acquire().await;
+        }
+
+        check_status(self.execute(self.client.get(url).query(query)).await?).await
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response, Error> {
+        if let Some(rate_limiters) = &self.rate_limiters {
+            rate_limiters.rest.acquire().await;
+        }
+
+        check_status(self.execute(request).await?).await
+    }
+
+    async fn submit<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, Error> {
+        let resp = self.send(request).await?;
+
+        resp.json().await.map_err(Error::Deserialize)
+    }
+
+    async fn submit_order<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        order: &OrderRequest,
+    ) -> Result<T, Error> {
+        let client_id = order.client_id().filter(|_| {
+            self.retry_policy
+                .as_ref()
+                .is_some_and(|policy| policy.retry_orders)
+        });
+        let resp = self
+            .send_with_retry(request, client_id.is_some(), client_id)
+            .await?;
+
+        resp.json().await.map_err(Error::Deserialize)
+    }
+
+    /// Sends `request`, retrying transient failures when `retry` is set. With
+    /// a `client_order_id` the order is looked up before each resend, and the
+    /// original error is returned instead if OANDA already has it.
+    async fn send_with_retry(
+        &self,
+        request: RequestBuilder,
+        retry: bool,
+        client_order_id: Option<&str>,
+    ) -> Result<Response, Error> {
+        let Some(policy) = self.retry_policy.as_ref().filter(|_| retry) else {
+            return self.send(request).await;
+        };
+
+        let mut attempt = 0;
+        loop {
+            let Some(attempt_request) = request.try_clone() else {
+                return self.send(request).await;
+            };
+
+            match self.send(attempt_request).await {
+                Err(error) if error.is_transient() && !policy.backoff.exhausted(attempt) => {
+                    if let Some(client_id) = client_order_id
+                        && !matches!(orders::order_exists(self, client_id).await, Ok(false))
+                    {
+                        return Err(error);
+                    }
+
+                    let delay = policy.delay(attempt, &error);
+                    attempt += 1;
+
+                    if let Some(on_retry) = &policy.on_retry {
+                        on_retry(&RetryEvent {
+                            attempt,
+                            delay,
+                            error: &error,
+                        });
+                    }
+
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
         }
     }
+
+    async fn execute(&self, request: RequestBuilder) -> Result<Response, Error> {
+        request
+            .header(AUTHORIZATION, format!("Bearer {}", self.auth_token))
+            .header(CONTENT_TYPE, "application/json")
+            .send()
+            .await
+            .map_err(Error::Request)
+    }
+}
+
+/// Percent-encodes `segment` so ids such as client ids can't change the
+/// request path or add a query.
+fn path_segment(segment: impl fmt::Display) -> String {
+    let mut url = reqwest::Url::parse("http://localhost").unwrap();
+    url.path_segments_mut().unwrap().push(&segment.to_string());
+
+    url.path()[1..].to_string()
+}
+
+async fn check_status(resp: Response) -> Result<Response, Error> {
+    if !resp.status().is_success() {
+        return Err(error_response(resp).await);
+    }
+
+    Ok(resp)
+}
+
+async fn error_response(resp: Response) -> Error {
+    let status = resp.status();
+    let retry_after = resp
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .map(Duration::from_secs);
+    let body = resp.bytes().await.unwrap_or_default();
+
+    Error::from_status(status, retry_after, ApiError::from_body(&body))
+}
+
+#[async_trait]
+impl Api for Client {
+    async fn instruments(&self) -> Result<Vec<Instrument>, Error> {
+        instruments::instruments(self, &AccountInstrumentsParams::default()).await
+    }
+
+    async fn instrument_candles(
+        &self,
+        instrument: &str,
+        params: &CandlesParams,
+    ) -> Result<Vec<Candlestick>, Error> {
+        instruments::instrument_candles(self, instrument, params).await
+    }
+
+    async fn order_book(
+        &self,
+        instrument: &str,
+        time: Option<DateTime<Utc>>,
+    ) -> Result<OrderBook, Error> {
+        instruments::order_book(self, instrument, time).await
+    }
+
+    async fn position_book(
+        &self,
+        instrument: &str,
+        time: Option<DateTime<Utc>>,
+    ) -> Result<PositionBook, Error> {
+        instruments::position_book(self, instrument, time).await
+    }
+
+    async fn pricing(&self, params: &PricingParams) -> Result<Pricing, Error> {
+        pricing::pricing(self, params).await
+    }
+
+    async fn create_order(&self, request: &OrderRequest) -> Result<CreateOrderResponse, Error> {
+        orders::create_order(self, request).await
+    }
+
+    async fn orders(&self, params: &OrdersParams) -> Result<Vec<Order>, Error> {
+        orders::orders(self, params).await
+    }
+
+    async fn pending_orders(&self) -> Result<Vec<Order>, Error> {
+        orders::pending_orders(self).await
+    }
+
+    async fn order(&self, specifier: &OrderSpecifier) -> Result<Order, Error> {
+        orders::order(self, specifier).await
+    }
+
+    async fn replace_order(
+        &self,
+        specifier: &OrderSpecifier,
+        request: &OrderRequest,
+    ) -> Result<ReplaceOrderResponse, Error> {
+        orders::replace_order(self, specifier, request).await
+    }
+
+    async fn cancel_order(&self, specifier: &OrderSpecifier) -> Result<CancelOrderResponse, Error> {
+        orders::cancel_order(self, specifier).await
+    }
+
+    async fn set_order_client_extensions(
+        &self,
+        specifier: &OrderSpecifier,
+        client_extensions: Option<&ClientExtensions>,
+        trade_client_extensions: Option<&ClientExtensions>,
+    ) -> Result<SetOrderClientExtensionsResponse, Error> {
+        orders::set_order_client_extensions(
+            self,
+            specifier,
+            client_extensions,
+            trade_client_extensions,
+        )
+        .await
+    }
+
+    async fn trades(&self, params: &TradesParams) -> Result<Vec<Trade>, Error> {
+        trades::trades(self, params).await
+    }
+
+    async fn open_trades(&self) -> Result<Vec<Trade>, Error> {
+        trades::open_trades(self).await
+    }
+
+    async fn trade(&self, specifier: &TradeSpecifier) -> Result<Trade, Error> {
+        trades::trade(self, specifier).await
+    }
+
+    async fn close_trade(
+        &self,
+        specifier: &TradeSpecifier,
+        units: CloseTradeUnits,
+    ) -> Result<CloseTradeResponse, Error> {
+        trades::close_trade(self, specifier, units).await
+    }
+
+    async fn set_trade_dependent_orders(
+        &self,
+        specifier: &TradeSpecifier,
+        orders: &TradeDependentOrders,
+    ) -> Result<SetTradeDependentOrdersResponse, Error> {
+        trades::set_trade_dependent_orders(self, specifier, orders).await
+    }
+
+    async fn positions(&self) -> Result<Vec<Position>, Error> {
+        positions::positions(self).await
+    }
+
+    async fn open_positions(&self) -> Result<Vec<Position>, Error> {
+        positions::open_positions(self).await
+    }
+
+    async fn position(&self, instrument: &str) -> Result<Position, Error> {
+        positions::position(self, instrument).await
+    }
+
+    async fn close_position(
+        &self,
+        instrument: &str,
+        request: &ClosePositionRequest,
+    ) -> Result<ClosePositionResponse, Error> {
+        positions::close_position(self, instrument, request).await
+    }
+
+    async fn accounts(&self) -> Result<Vec<AccountProperties>, Error> {
+        accounts::accounts(self).await
+    }
+
+    async fn account(&self) -> Result<Account, Error> {
+        accounts::account(self).await
+    }
+
+    async fn account_summary(&self) -> Result<AccountSummary, Error> {
+        accounts::account_summary(self).await
+    }
+
+    async fn account_instruments(
+        &self,
+        params: &AccountInstrumentsParams,
+    ) -> Result<Vec<Instrument>, Error> {
+        instruments::instruments(self, params).await
+    }
+
+    async fn account_changes(
+        &self,
+        since_transaction_id: &str,
+    ) -> Result<AccountChangesResponse, Error> {
+        accounts::account_changes(self, since_transaction_id).await
+    }
+
+    async fn transactions(&self, params: &TransactionsParams) -> Result<TransactionPages, Error> {
+        transactions::transactions(self, params).await
+    }
+
+    async fn transaction(&self, id: &str) -> Result<Transaction, Error> {
+        transactions::transaction(self, id).await
+    }
+
+    async fn transactions_id_range(
+        &self,
+        params: &TransactionsIdRangeParams,
+    ) -> Result<Vec<Transaction>, Error> {
+        transactions::transactions_id_range(self, params).await
+    }
+
+    async fn transactions_since_id(
+        &self,
+        params: &TransactionsSinceIdParams,
+    ) -> Result<Vec<Transaction>, Error> {
+        transactions::transactions_since_id(self, params).await
+    }
 }
@@ -0,0 +1,642 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    oanda::{
+        client::path_segment, CancelOrderResponse, Client, ClientExtensions, CreateOrderResponse,
+        Order, OrderRequest, OrderSpecifier, OrdersParams, ReplaceOrderResponse,
+        SetOrderClientExtensionsResponse,
+    },
+    Error,
+};
+
+#[derive(Serialize)]
+struct OrderBody<'a> {
+    order: &'a OrderRequest,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClientExtensionsBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_extensions: Option<&'a ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trade_client_extensions: Option<&'a ClientExtensions>,
+}
+
+#[derive(Deserialize)]
+struct OrdersData {
+    orders: Vec<Order>,
+}
+
+#[derive(Deserialize)]
+struct OrderData {
+    order: Order,
+}
+
+pub(super) async fn create_order(
+    client: &Client,
+    request: &OrderRequest,
+) -> Result<CreateOrderResponse, Error> {
+    let url = format!("{}/v3/accounts/{}/orders", client.url, client.account_id);
+    let body = OrderBody { order: request };
+
+    client
+        .submit_order(client.client.post(url).json(&body), request)
+        .await
+}
+
+pub(super) async fn orders(client: &Client, params: &OrdersParams) -> Result<Vec<Order>, Error> {
+    let path = format!("/v3/accounts/{}/orders", client.account_id);
+    let data: OrdersData = client.get(&path, params).await?;
+
+    Ok(data.orders)
+}
+
+pub(super) async fn pending_orders(client: &Client) -> Result<Vec<Order>, Error> {
+    let path = format!("/v3/accounts/{}/pendingOrders", client.account_id);
+    let data: OrdersData = client.get(&path, &()).await?;
+
+    Ok(data.orders)
+}
+
+pub(super) async fn order(client: &Client, specifier: &OrderSpecifier) -> Result<Order, Error> {
+    let path = format!(
+        "/v3/accounts/{}/orders/{}",
+        client.account_id,
+        path_segment(specifier)
+    );
+    let data: OrderData = client.get(&path, &()).await?;
+
+    Ok(data.order)
+}
+
+/// Looks up an order by client id without retrying, to tell whether a failed
+/// order request reached OANDA.
+pub(super) async fn order_exists(client: &Client, client_id: &str) -> Result<bool, Error> {
+    let url = format!(
+        "{}/v3/accounts/{}/orders/{}",
+        client.url,
+        client.account_id,
+        path_segment(OrderSpecifier::ClientId(client_id.to_string()))
+    );
+
+    match client.send(client.client.get(url)).await {
+        Ok(_) => Ok(true),
+        Err(Error::NotFound(_)) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+pub(super) async fn replace_order(
+    client: &Client,
+    specifier: &OrderSpecifier,
+    request: &OrderRequest,
+) -> Result<ReplaceOrderResponse, Error> {
+    let url = format!(
+        "{}/v3/accounts/{}/orders/{}",
+        client.url,
+        client.account_id,
+        path_segment(specifier)
+    );
+    let body = OrderBody { order: request };
+
+    client
+        .submit_order(client.client.put(url).json(&body), request)
+        .await
+}
+
+pub(super) async fn cancel_order(
+    client: &Client,
+    specifier: &OrderSpecifier,
+) -> Result<CancelOrderResponse, Error> {
+    let url = format!(
+        "{}/v3/accounts/{}/orders/{}/cancel",
+        client.url,
+        client.account_id,
+        path_segment(specifier)
+    );
+
+    client.submit(client.client.put(url)).await
+}
+
+pub(super) async fn set_order_client_extensions(
+    client: &Client,
+    specifier: &OrderSpecifier,
+    client_extensions: Option<&ClientExtensions>,
+    trade_client_extensions: Option<&ClientExtensions>,
+) -> Result<SetOrderClientExtensionsResponse, Error> {
+    let url = format!(
+        "{}/v3/accounts/{}/orders/{}/clientExtensions",
+        client.url,
+        client.account_id,
+        path_segment(specifier)
+    );
+    let body = ClientExtensionsBody {
+        client_extensions,
+        trade_client_extensions,
+    };
+
+    client.submit(client.client.put(url).json(&body)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+
+    use crate::{
+        number::number,
+        oanda::{
+            order::fixtures::{
+                cancel_order_response_json, create_order_response_json, order_rejection_json,
+                orders_json, replace_order_response_json,
+                set_order_client_extensions_response_json,
+            },
+            Api, Client, ClientExtensions, LimitOrderRequest, MarketOrderRequest, Order,
+            OrderRequest, OrderSpecifier, OrderState, OrderStateFilter, OrdersParams,
+            StopLossDetails, TakeProfitDetails, TimeInForce, Transaction, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    #[tokio::test]
+    async fn test_create_order() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "POST",
+                format!("/v3/accounts/{}/orders", ACCOUNT_ID).as_str(),
+            )
+            .match_body(Matcher::Json(json!({
+                "order": {
+                    "type": "MARKET",
+                    "instrument": "EUR_USD",
+                    "units": "100",
+                    "timeInForce": "FOK",
+                    "clientExtensions": {
+                        "id": "my-order",
+                        "tag": "strategy-a"
+                    },
+                    "takeProfitOnFill": {
+                        "price": "1.1"
+                    },
+                    "stopLossOnFill": {
+                        "distance": "0.005"
+                    }
+                }
+            })))
+            .with_status(201)
+            .with_body(create_order_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let request = OrderRequest::Market(MarketOrderRequest {
+            instrument: "EUR_USD".to_string(),
+            units: number("100"),
+            time_in_force: Some(TimeInForce::Fok),
+            client_extensions: Some(ClientExtensions {
+                id: Some("my-order".to_string()),
+                tag: Some("strategy-a".to_string()),
+                comment: None,
+            }),
+            take_profit_on_fill: Some(TakeProfitDetails {
+                price: number("1.1"),
+                ..Default::default()
+            }),
+            stop_loss_on_fill: Some(StopLossDetails {
+                distance: Some(number("0.005")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let resp = client.create_order(&request).await.unwrap();
+
+        assert!(matches!(
+            resp.order_create_transaction,
+            Transaction::MarketOrder(ref transaction)
+                if transaction.order.units == number("100") && transaction.header.id == "6368"
+        ));
+
+        let fill = resp.order_fill_transaction.unwrap();
+        assert_eq!("6368", fill.order_id);
+        assert_eq!("6369", fill.trade_opened.unwrap().trade_id);
+        assert!(resp.order_cancel_transaction.is_none());
+        assert_eq!("6369", resp.last_transaction_id);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_create_order_rejected_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "POST",
+                format!("/v3/accounts/{}/orders", ACCOUNT_ID).as_str(),
+            )
+            .with_status(400)
+            .with_body(order_rejection_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let request = OrderRequest::Limit(LimitOrderRequest {
+            instrument: "EUR_USD".to_string(),
+            units: number("100"),
+            price: number("1"),
+            ..Default::default()
+        });
+
+        let err = client.create_order(&request).await;
+        assert!(err.is_err_and(|err| matches!(
+            err,
+            Error::Validation(error)
+                if matches!(
+                    error.reject_transaction(),
+                    Some(Transaction::LimitOrderReject(transaction))
+                        if transaction.reject_reason.as_deref() == Some("PRICE_PRECISION_EXCEEDED")
+                )
+        )))
+    }
+
+    #[tokio::test]
+    async fn test_create_order_unauthorized_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "POST",
+                format!("/v3/accounts/{}/orders", ACCOUNT_ID).as_str(),
+            )
+            .with_status(401)
+            .with_body(r#"{"errorMessage": "Insufficient authorization to perform request."}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let request = OrderRequest::Market(MarketOrderRequest {
+            instrument: "EUR_USD".to_string(),
+            units: number("100"),
+            ..Default::default()
+        });
+
+        let err = client.create_order(&request).await;
+        assert!(err.is_err_and(|err| matches!(err, Error::Unauthorized(_))))
+    }
+
+    #[tokio::test]
+    async fn test_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/orders", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("state".into(), "ALL".into()),
+                Matcher::UrlEncoded("instrument".into(), "EUR_USD".into()),
+            ]))
+            .with_status(200)
+            .with_body(format!(r#"{{"orders": {}}}"#, orders_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = OrdersParams {
+            state: Some(OrderStateFilter::All),
+            instrument: Some("EUR_USD".to_string()),
+            ..Default::default()
+        };
+
+        let orders = client.orders(&params).await.unwrap();
+
+        assert_eq!(2, orders.len());
+        assert!(
+            matches!(&orders[0], Order::Limit(order) if order.request.price == number("1.075"))
+        );
+        assert!(matches!(&orders[1], Order::TakeProfit(order) if order.request.trade_id == "6369"));
+        assert_eq!("6372", orders[0].id());
+        assert_eq!(OrderState::Pending, orders[1].state());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_orders_unknown_type() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mut orders: Vec<serde_json::Value> = serde_json::from_str(&orders_json()).unwrap();
+        orders.push(json!({
+            "id": "6373",
+            "createTime": "2024-01-02T10:06:00.000000000Z",
+            "type": "FIXED_PRICE",
+            "instrument": "EUR_USD",
+            "units": "100",
+            "price": "1.08000",
+            "state": "FILLED"
+        }));
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/orders", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({ "orders": orders }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let resp = client.orders(&OrdersParams::default()).await.unwrap();
+
+        assert_eq!(3, resp.len());
+        assert!(matches!(&resp[2], Order::Unknown(order) if order.order_type == "FIXED_PRICE"));
+        assert_eq!("6373", resp[2].id());
+        assert_eq!(OrderState::Filled, resp[2].state());
+        assert_eq!(orders[2], serde_json::to_value(&resp[2]).unwrap());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_pending_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pendingOrders", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(format!(r#"{{"orders": {}}}"#, orders_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let orders = client.pending_orders().await.unwrap();
+        assert_eq!(2, orders.len());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_order_by_client_id() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let order: serde_json::Value = serde_json::from_str(&orders_json()).unwrap();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/orders/@my-limit", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({ "order": order[0] }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let order = client
+            .order(&OrderSpecifier::ClientId("my-limit".to_string()))
+            .await
+            .unwrap();
+        assert_eq!("6372", order.id());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_order_by_client_id_is_percent_encoded() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let order: serde_json::Value = serde_json::from_str(&orders_json()).unwrap();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/orders/@my%2Flimit%3Fx%23y%20z", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({ "order": order[0] }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let order = client
+            .order(&OrderSpecifier::ClientId("my/limit?x#y z".to_string()))
+            .await
+            .unwrap();
+        assert_eq!("6372", order.id());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_replace_order() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "PUT",
+                format!("/v3/accounts/{}/orders/6372", ACCOUNT_ID).as_str(),
+            )
+            .match_body(Matcher::PartialJson(json!({
+                "order": {
+                    "type": "LIMIT",
+                    "units": "200",
+                    "price": "1.074"
+                }
+            })))
+            .with_status(201)
+            .with_body(replace_order_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let request = OrderRequest::Limit(LimitOrderRequest {
+            instrument: "EUR_USD".to_string(),
+            units: number("200"),
+            price: number("1.074"),
+            ..Default::default()
+        });
+
+        let resp = client
+            .replace_order(&OrderSpecifier::Id("6372".to_string()), &request)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some("6374"),
+            resp.order_cancel_transaction
+                .replaced_by_order_id
+                .as_deref()
+        );
+        assert!(matches!(
+            resp.order_create_transaction,
+            Transaction::LimitOrder(ref transaction)
+                if transaction.replaces_order_id.as_deref() == Some("6372")
+        ));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_cancel_order() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "PUT",
+                format!("/v3/accounts/{}/orders/6374/cancel", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(cancel_order_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let resp = client
+            .cancel_order(&OrderSpecifier::Id("6374".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!("6374", resp.order_cancel_transaction.order_id);
+        assert_eq!("6375", resp.last_transaction_id);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_set_order_client_extensions() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "PUT",
+                format!(
+                    "/v3/accounts/{}/orders/@my-limit/clientExtensions",
+                    ACCOUNT_ID
+                )
+                .as_str(),
+            )
+            .match_body(Matcher::Json(json!({
+                "clientExtensions": {
+                    "comment": "moved to desk b"
+                }
+            })))
+            .with_status(200)
+            .with_body(set_order_client_extensions_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let client_extensions = ClientExtensions {
+            comment: Some("moved to desk b".to_string()),
+            ..Default::default()
+        };
+
+        let resp = client
+            .set_order_client_extensions(
+                &OrderSpecifier::ClientId("my-limit".to_string()),
+                Some(&client_extensions),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let transaction = resp.order_client_extensions_modify_transaction;
+        assert_eq!("6372", transaction.order_id);
+        assert_eq!(
+            Some("moved to desk b"),
+            transaction
+                .client_extensions_modify
+                .and_then(|extensions| extensions.comment)
+                .as_deref()
+        );
+
+        mock.assert();
+    }
+}
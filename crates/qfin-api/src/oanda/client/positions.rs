@@ -0,0 +1,250 @@
+use serde::Deserialize;
+
+use crate::{
+    oanda::{Client, ClosePositionRequest, ClosePositionResponse, Position},
+    Error,
+};
+
+#[derive(Deserialize)]
+struct PositionsData {
+    positions: Vec<Position>,
+}
+
+#[derive(Deserialize)]
+struct PositionData {
+    position: Position,
+}
+
+pub(super) async fn positions(client: &Client) -> Result<Vec<Position>, Error> {
+    let path = format!("/v3/accounts/{}/positions", client.account_id);
+    let data: PositionsData = client.get(&path, &()).await?;
+
+    Ok(data.positions)
+}
+
+pub(super) async fn open_positions(client: &Client) -> Result<Vec<Position>, Error> {
+    let path = format!("/v3/accounts/{}/openPositions", client.account_id);
+    let data: PositionsData = client.get(&path, &()).await?;
+
+    Ok(data.positions)
+}
+
+pub(super) async fn position(client: &Client, instrument: &str) -> Result<Position, Error> {
+    let path = format!(
+        "/v3/accounts/{}/positions/{}",
+        client.account_id, instrument
+    );
+    let data: PositionData = client.get(&path, &()).await?;
+
+    Ok(data.position)
+}
+
+pub(super) async fn close_position(
+    client: &Client,
+    instrument: &str,
+    request: &ClosePositionRequest,
+) -> Result<ClosePositionResponse, Error> {
+    let url = format!(
+        "{}/v3/accounts/{}/positions/{}/close",
+        client.url, client.account_id, instrument
+    );
+
+    client.submit(client.client.put(url).json(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+
+    use crate::{
+        number::number,
+        oanda::{
+            position::fixtures::{close_position_response_json, positions_json},
+            Api, Client, ClosePositionRequest, ClosePositionUnits, Transaction, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    #[tokio::test]
+    async fn test_positions() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/positions", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(format!(r#"{{"positions": {}}}"#, positions_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let positions = client.positions().await.unwrap();
+
+        assert_eq!(1, positions.len());
+        assert_eq!(number("100"), positions[0].long.units);
+        assert_eq!(vec!["6369".to_string()], positions[0].long.trade_ids);
+        assert_eq!(number("0"), positions[0].short.units);
+        assert!(positions[0].short.average_price.is_none());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_open_positions() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/openPositions", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(format!(r#"{{"positions": {}}}"#, positions_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let positions = client.open_positions().await.unwrap();
+        assert_eq!(1, positions.len());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_position() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let positions: serde_json::Value = serde_json::from_str(&positions_json()).unwrap();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/positions/EUR_USD", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({ "position": positions[0] }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let position = client.position("EUR_USD").await.unwrap();
+        assert_eq!("EUR_USD", position.instrument);
+        assert_eq!(Some(number("2.1683")), position.margin_used);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_close_position() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "PUT",
+                format!("/v3/accounts/{}/positions/EUR_USD/close", ACCOUNT_ID).as_str(),
+            )
+            .match_body(Matcher::Json(json!({
+                "longUnits": "ALL",
+                "shortUnits": "NONE"
+            })))
+            .with_status(200)
+            .with_body(close_position_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let request = ClosePositionRequest {
+            long_units: Some(ClosePositionUnits::All),
+            short_units: Some(ClosePositionUnits::None),
+            ..Default::default()
+        };
+
+        let resp = client.close_position("EUR_USD", &request).await.unwrap();
+
+        assert!(matches!(
+            resp.long_order_create_transaction,
+            Some(Transaction::MarketOrder(ref transaction))
+                if transaction
+                    .long_position_closeout
+                    .as_ref()
+                    .is_some_and(|closeout| closeout.units == "ALL")
+        ));
+        assert_eq!(
+            1,
+            resp.long_order_fill_transaction
+                .unwrap()
+                .trades_closed
+                .len()
+        );
+        assert!(resp.short_order_create_transaction.is_none());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_close_position_server_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "PUT",
+                format!("/v3/accounts/{}/positions/EUR_USD/close", ACCOUNT_ID).as_str(),
+            )
+            .with_status(500)
+            .with_body("Internal Server Error")
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let err = client
+            .close_position("EUR_USD", &ClosePositionRequest::default())
+            .await;
+        assert!(err.is_err_and(|err| matches!(
+            err,
+            Error::Server {
+                status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                ..
+            }
+        )))
+    }
+}
@@ -0,0 +1,626 @@
+use std::time::Duration;
+
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+
+use crate::{
+    oanda::{
+        client::{stream::json_lines, Backoff},
+        Client, Pricing, PricingParams, PricingStreamEvent, PricingStreamMessage,
+        PricingStreamParams,
+    },
+    Error,
+};
+
+pub(super) async fn pricing(client: &Client, params: &PricingParams) -> Result<Pricing, Error> {
+    let path = format!("/v3/accounts/{}/pricing", client.account_id);
+    client.get(&path, params).await
+}
+
+impl Client {
+    pub async fn pricing_stream(
+        &self,
+        params: &PricingStreamParams,
+    ) -> Result<BoxStream<'static, Result<PricingStreamMessage, Error>>, Error> {
+        let path = format!("/v3/accounts/{}/pricing/stream", self.account_id);
+        let resp = self.get_stream(&path, params).await?;
+
+        Ok(json_lines(resp, params.heartbeat_timeout))
+    }
+
+    /// Streams prices for the same instruments across dropped connections and
+    /// missed heartbeats. Every (re)connect and disconnect is reported as an
+    /// event. Messages that can't be parsed are yielded as errors on the same
+    /// connection; any other error ends the stream once it stops reconnecting.
+    pub fn reconnecting_pricing_stream(
+        &self,
+        params: &PricingStreamParams,
+        backoff: Backoff,
+    ) -> BoxStream<'static, Result<PricingStreamEvent, Error>> {
+        let supervisor = Supervisor {
+            client: self.clone(),
+            params: params.clone(),
+            backoff,
+            stream: None,
+            attempt: 0,
+            delay: None,
+            done: false,
+        };
+
+        stream::unfold(supervisor, Supervisor::next_event).boxed()
+    }
+}
+
+struct Supervisor {
+    client: Client,
+    params: PricingStreamParams,
+    backoff: Backoff,
+    stream: Option<BoxStream<'static, Result<PricingStreamMessage, Error>>>,
+    attempt: u32,
+    delay: Option<Duration>,
+    done: bool,
+}
+
+impl Supervisor {
+    async fn next_event(mut self) -> Option<(Result<PricingStreamEvent, Error>, Self)> {
+        if self.done {
+            return None;
+        }
+
+        let Some(stream) = self.stream.as_mut() else {
+            if let Some(delay) = self.delay.take() {
+                tokio::time::sleep(delay).await;
+            }
+
+            return match self.client.pricing_stream(&self.params).await {
+                Ok(stream) => {
+                    self.stream = Some(stream);
+                    Some((Ok(PricingStreamEvent::Connected), self))
+                }
+                Err(err) => Some(self.disconnect(err)),
+            };
+        };
+
+        match stream.next().await {
+            Some(Ok(message)) => {
+                // Only a stream that delivers something counts as recovered, so
+                // a server that accepts and then drops still exhausts retries.
+                self.attempt = 0;
+                Some((Ok(PricingStreamEvent::Message(message)), self))
+            }
+            // An unparseable message says nothing about the connection, and
+            // reconnecting would only receive it again.
+            Some(Err(err @ (Error::StreamDeserialize(_) | Error::Deserialize(_)))) => {
+                Some((Err(err), self))
+            }
+            Some(Err(err)) => Some(self.disconnect(err)),
+            None => Some(self.disconnect(Error::StreamClosed)),
+        }
+    }
+
+    fn disconnect(mut self, error: Error) -> (Result<PricingStreamEvent, Error>, Self) {
+        self.stream = None;
+
+        if !is_reconnectable(&error) || self.backoff.exhausted(self.attempt) {
+            self.done = true;
+            return (Err(error), self);
+        }
+
+        let reconnect_in = self.backoff.delay(self.attempt);
+        self.attempt += 1;
+        self.delay = Some(reconnect_in);
+
+        let event = PricingStreamEvent::Disconnected {
+            error,
+            reconnect_in,
+        };
+        (Ok(event), self)
+    }
+}
+
+fn is_reconnectable(error: &Error) -> bool {
+    !matches!(
+        error,
+        Error::Unauthorized(_)
+            | Error::Forbidden(_)
+            | Error::NotFound(_)
+            | Error::Validation(_)
+            | Error::Status { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+    use futures::StreamExt;
+    use mockito::Matcher;
+
+    use crate::{
+        oanda::{
+            client::Backoff,
+            pricing::fixtures::{client_price_json, pricing_heartbeat_json, pricing_json},
+            Api, Client, PricingParams, PricingStreamEvent, PricingStreamMessage,
+            PricingStreamParams, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    fn stream_line(json: String) -> String {
+        format!("{}\n", json.split_whitespace().collect::<String>())
+    }
+
+    #[tokio::test]
+    async fn test_pricing() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("instruments".into(), "EUR_USD".into()),
+                Matcher::UrlEncoded("since".into(), "2024-01-02T10:00:00Z".into()),
+                Matcher::UrlEncoded("includeHomeConversions".into(), "true".into()),
+            ]))
+            .with_status(200)
+            .with_body(pricing_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = PricingParams {
+            instruments: vec!["EUR_USD".to_string()],
+            since: Some(Utc.with_ymd_and_hms(2024, 1, 2, 10, 0, 0).unwrap()),
+            include_home_conversions: Some(true),
+        };
+
+        let pricing = client.pricing(&params).await.unwrap();
+
+        assert_eq!(1, pricing.prices.len());
+        assert_eq!("EUR_USD", pricing.prices[0].instrument);
+        assert_eq!(2, pricing.home_conversions.unwrap().len());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_pricing_server_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_status(500)
+            .with_body("Internal Server Error")
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let err = client.pricing(&PricingParams::default()).await;
+        assert!(err.is_err_and(|err| matches!(
+            err,
+            Error::Server {
+                status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                ..
+            }
+        )))
+    }
+
+    #[tokio::test]
+    async fn test_pricing_stream() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::UrlEncoded(
+                "instruments".into(),
+                "EUR_USD,USD_JPY".into(),
+            ))
+            .with_status(200)
+            .with_body(format!(
+                "{}{}{}",
+                stream_line(client_price_json()),
+                stream_line(pricing_heartbeat_json()),
+                stream_line(client_price_json()),
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = PricingStreamParams {
+            instruments: vec!["EUR_USD".to_string(), "USD_JPY".to_string()],
+            ..Default::default()
+        };
+
+        let messages: Vec<_> = client
+            .pricing_stream(&params)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(3, messages.len());
+        assert!(
+            matches!(&messages[0], Ok(PricingStreamMessage::Price(price)) if price.instrument == "EUR_USD")
+        );
+        assert!(matches!(
+            &messages[1],
+            Ok(PricingStreamMessage::Heartbeat(_))
+        ));
+        assert!(matches!(&messages[2], Ok(PricingStreamMessage::Price(_))));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_pricing_stream_heartbeat_timeout_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_chunked_body(|w| {
+                w.write_all(stream_line(pricing_heartbeat_json()).as_bytes())?;
+                w.flush()?;
+                std::thread::sleep(Duration::from_millis(500));
+                Ok(())
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = PricingStreamParams {
+            instruments: vec!["EUR_USD".to_string()],
+            heartbeat_timeout: Duration::from_millis(100),
+            ..Default::default()
+        };
+
+        let mut stream = client.pricing_stream(&params).await.unwrap();
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(PricingStreamMessage::Heartbeat(_)))
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::HeartbeatTimeout(_)))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pricing_stream_deserialize_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body("bad json\n")
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let mut stream = client
+            .pricing_stream(&PricingStreamParams::default())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::StreamDeserialize(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_pricing_stream() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::UrlEncoded("instruments".into(), "EUR_USD".into()))
+            .with_status(200)
+            .with_body(stream_line(client_price_json()))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = PricingStreamParams {
+            instruments: vec!["EUR_USD".to_string()],
+            ..Default::default()
+        };
+        let backoff = Backoff {
+            initial: Duration::from_millis(10),
+            ..Default::default()
+        };
+
+        let events: Vec<_> = client
+            .reconnecting_pricing_stream(&params, backoff)
+            .take(5)
+            .collect()
+            .await;
+
+        assert!(matches!(events[0], Ok(PricingStreamEvent::Connected)));
+        assert!(matches!(
+            events[1],
+            Ok(PricingStreamEvent::Message(PricingStreamMessage::Price(_)))
+        ));
+        assert!(matches!(
+            events[2],
+            Ok(PricingStreamEvent::Disconnected {
+                error: Error::StreamClosed,
+                reconnect_in,
+            }) if reconnect_in == Duration::from_millis(10)
+        ));
+        assert!(matches!(events[3], Ok(PricingStreamEvent::Connected)));
+        assert!(matches!(
+            events[4],
+            Ok(PricingStreamEvent::Message(PricingStreamMessage::Price(_)))
+        ));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_pricing_stream_keeps_connection_on_bad_message() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(format!(
+                "{}{{\"type\":\"PRICE\"}}\n{}",
+                stream_line(pricing_heartbeat_json()),
+                stream_line(client_price_json())
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let backoff = Backoff {
+            max_retries: Some(0),
+            ..Default::default()
+        };
+
+        let events: Vec<_> = client
+            .reconnecting_pricing_stream(&PricingStreamParams::default(), backoff)
+            .collect()
+            .await;
+
+        assert_eq!(5, events.len());
+        assert!(matches!(events[0], Ok(PricingStreamEvent::Connected)));
+        assert!(matches!(
+            events[1],
+            Ok(PricingStreamEvent::Message(
+                PricingStreamMessage::Heartbeat(_)
+            ))
+        ));
+        assert!(matches!(events[2], Err(Error::StreamDeserialize(_))));
+        assert!(matches!(
+            events[3],
+            Ok(PricingStreamEvent::Message(PricingStreamMessage::Price(_)))
+        ));
+        assert!(matches!(events[4], Err(Error::StreamClosed)));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_pricing_stream_gives_up() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let backoff = Backoff {
+            initial: Duration::from_millis(10),
+            max_retries: Some(1),
+            ..Default::default()
+        };
+
+        let events: Vec<_> = client
+            .reconnecting_pricing_stream(&PricingStreamParams::default(), backoff)
+            .collect()
+            .await;
+
+        assert_eq!(2, events.len());
+        assert!(matches!(
+            events[0],
+            Ok(PricingStreamEvent::Disconnected {
+                error: Error::Server {
+                    status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                    ..
+                },
+                ..
+            })
+        ));
+        assert!(matches!(
+            events[1],
+            Err(Error::Server {
+                status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_pricing_stream_accept_then_drop_gives_up() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body("")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let backoff = Backoff {
+            initial: Duration::from_millis(10),
+            max_retries: Some(2),
+            ..Default::default()
+        };
+
+        let events: Vec<_> = client
+            .reconnecting_pricing_stream(&PricingStreamParams::default(), backoff)
+            .collect()
+            .await;
+
+        assert_eq!(6, events.len());
+        assert!(matches!(events[0], Ok(PricingStreamEvent::Connected)));
+        assert!(matches!(
+            events[1],
+            Ok(PricingStreamEvent::Disconnected {
+                error: Error::StreamClosed,
+                reconnect_in,
+            }) if reconnect_in == Duration::from_millis(10)
+        ));
+        assert!(matches!(
+            events[3],
+            Ok(PricingStreamEvent::Disconnected {
+                error: Error::StreamClosed,
+                reconnect_in,
+            }) if reconnect_in == Duration::from_millis(20)
+        ));
+        assert!(matches!(events[5], Err(Error::StreamClosed)));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_pricing_stream_unauthorized_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/pricing/stream", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let events: Vec<_> = client
+            .reconnecting_pricing_stream(&PricingStreamParams::default(), Backoff::default())
+            .collect()
+            .await;
+
+        assert_eq!(1, events.len());
+        assert!(matches!(events[0], Err(Error::Unauthorized(_))));
+    }
+}
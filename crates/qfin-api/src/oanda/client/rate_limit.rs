@@ -0,0 +1,248 @@
+use std::time::Duration;
+
+use tokio::{sync::Mutex, time::Instant};
+
+/// Allows `requests` per `per`, in bursts of up to `requests`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    pub fn per_second(requests: u32) -> Self {
+        RateLimit {
+            requests,
+            per: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Limits shared by every clone of a client. Streams are limited by how often
+/// new connections are opened, separately from REST requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitPolicy {
+    pub rest: RateLimit,
+    pub stream: RateLimit,
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        // OANDA allows 100 requests and 2 new connections per second.
+        RateLimitPolicy {
+            rest: RateLimit::per_second(100),
+            stream: RateLimit::per_second(2),
+        }
+    }
+}
+
+/// An async token bucket. Callers wait for a token in the order they arrive
+/// instead of failing.
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    tokens_per_sec: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        let capacity = f64::from(limit.requests.max(1));
+        RateLimiter {
+            capacity,
+            tokens_per_sec: capacity / limit.per.as_secs_f64().max(f64::EPSILON),
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    pub async fn acquire(&self) {
+        // Holding the lock while waiting queues the other callers behind us.
+        let mut bucket = self.bucket.lock().await;
+        self.refill(&mut bucket);
+
+        if bucket.tokens < 1.0 {
+            let wait = (1.0 - bucket.tokens) / self.tokens_per_sec;
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+            self.refill(&mut bucket);
+        }
+
+        bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.tokens_per_sec).min(self.capacity);
+        bucket.updated = now;
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct RateLimiters {
+    pub(super) rest: RateLimiter,
+    pub(super) stream: RateLimiter,
+}
+
+impl RateLimiters {
+    pub(super) fn new(policy: RateLimitPolicy) -> Self {
+        RateLimiters {
+            rest: RateLimiter::new(policy.rest),
+            stream: RateLimiter::new(policy.stream),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use crate::oanda::{
+        client::{ClientBuilder, RateLimit, RateLimitPolicy, RateLimiter},
+        instrument::fixtures::instruments_json,
+        Api, Client, Url,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_burst() {
+        let limiter = RateLimiter::new(RateLimit::per_second(2));
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(Duration::ZERO, start.elapsed());
+
+        limiter.acquire().await;
+        assert_eq!(Duration::from_millis(500), start.elapsed());
+
+        limiter.acquire().await;
+        assert_eq!(Duration::from_secs(1), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_refill() {
+        let limiter = RateLimiter::new(RateLimit::per_second(2));
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        // Idle time refills the bucket, but never beyond its capacity.
+        tokio::time::advance(Duration::from_secs(10)).await;
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(Duration::ZERO, start.elapsed());
+
+        limiter.acquire().await;
+        assert_eq!(Duration::from_millis(500), start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_queues_concurrent_callers() {
+        let limiter = RateLimiter::new(RateLimit {
+            requests: 1,
+            per: Duration::from_millis(100),
+        });
+        let start = Instant::now();
+
+        let elapsed = futures::future::join_all((0..3).map(|_| async {
+            limiter.acquire().await;
+            start.elapsed()
+        }))
+        .await;
+
+        assert_eq!(
+            vec![
+                Duration::ZERO,
+                Duration::from_millis(100),
+                Duration::from_millis(200)
+            ],
+            elapsed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_rate_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/instruments", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(format!(r#"{{"instruments": {}}}"#, instruments_json()))
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        )
+        .with_rate_limit_policy(RateLimitPolicy {
+            rest: RateLimit {
+                requests: 1,
+                per: Duration::from_millis(50),
+            },
+            ..Default::default()
+        });
+
+        // Clones share the same buckets.
+        let start = Instant::now();
+        let other = client.clone();
+        let (a, b, c) = tokio::join!(
+            client.instruments(),
+            other.instruments(),
+            client.instruments()
+        );
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        mock.assert();
+    }
+
+    #[test]
+    fn test_client_rate_limit_default() {
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Practice,
+        );
+        assert!(client.rate_limiters.is_some());
+        assert!(client.without_rate_limit().rate_limiters.is_none());
+
+        let client = ClientBuilder::new()
+            .account_id(ACCOUNT_ID)
+            .auth_token(AUTH_TOKEN)
+            .build()
+            .unwrap();
+        assert!(client.rate_limiters.is_some());
+
+        let client = ClientBuilder::new()
+            .account_id(ACCOUNT_ID)
+            .auth_token(AUTH_TOKEN)
+            .without_rate_limit()
+            .build()
+            .unwrap();
+        assert!(client.rate_limiters.is_none());
+    }
+}
@@ -0,0 +1,402 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use crate::{oanda::client::Backoff, Error};
+
+pub type RetryHook = Arc<dyn Fn(&RetryEvent<'_>) + Send + Sync>;
+
+/// Passed to [`RetryPolicy::on_retry`] before each retry.
+#[derive(Debug)]
+pub struct RetryEvent<'a> {
+    /// The retry about to be made, starting at 1.
+    pub attempt: u32,
+    pub delay: Duration,
+    pub error: &'a Error,
+}
+
+/// Retries requests that fail with a transient error (see
+/// [`Error::is_transient`]).
+///
+/// Only GET requests are retried unless `retry_orders` is set, which also
+/// retries order creation and replacement when the order carries a client id.
+/// A failed order request may still have reached OANDA, and a market order
+/// fills at once, so a duplicate client id is not enough to stop a second
+/// fill. Before resending, the order is looked up by its client id and the
+/// original error is returned if OANDA already has it, or if the lookup
+/// itself fails.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    pub backoff: Backoff,
+    /// Fraction of each delay that is randomized, from 0 (none) to 1 (full).
+    pub jitter: f64,
+    pub retry_orders: bool,
+    pub on_retry: Option<RetryHook>,
+}
+
+impl RetryPolicy {
+    /// Returns the delay before retry `attempt`, counting from 0. Rate limited
+    /// requests wait at least as long as OANDA's `Retry-After`.
+    pub fn delay(&self, attempt: u32, error: &Error) -> Duration {
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let delay = self
+            .backoff
+            .delay(attempt)
+            .mul_f64(1.0 - jitter * fastrand::f64());
+
+        match error {
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            } => delay.max(*retry_after),
+            _ => delay,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            backoff: Backoff {
+                initial: Duration::from_millis(250),
+                max: Duration::from_secs(10),
+                multiplier: 2.0,
+                max_retries: Some(3),
+            },
+            jitter: 0.5,
+            retry_orders: false,
+            on_retry: None,
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("backoff", &self.backoff)
+            .field("jitter", &self.jitter)
+            .field("retry_orders", &self.retry_orders)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use crate::{
+        number::number,
+        oanda::{
+            client::{Backoff, RetryPolicy},
+            instrument::fixtures::instruments_json,
+            order::fixtures::create_order_response_json,
+            Api, Client, ClientExtensions, MarketOrderRequest, OrderRequest, Url,
+        },
+        ApiError, Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    fn retry_policy(retries: Arc<AtomicU32>) -> RetryPolicy {
+        RetryPolicy {
+            backoff: Backoff {
+                initial: Duration::from_millis(1),
+                max: Duration::from_millis(1),
+                multiplier: 1.0,
+                max_retries: Some(2),
+            },
+            jitter: 0.0,
+            retry_orders: false,
+            on_retry: Some(Arc::new(move |_| {
+                retries.fetch_add(1, Ordering::SeqCst);
+            })),
+        }
+    }
+
+    fn market_order(client_id: Option<&str>) -> OrderRequest {
+        OrderRequest::Market(MarketOrderRequest {
+            instrument: "EUR_USD".to_string(),
+            units: number("100"),
+            client_extensions: client_id.map(|id| ClientExtensions {
+                id: Some(id.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..Default::default()
+        };
+        let error = Error::from_status(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            None,
+            ApiError::default(),
+        );
+
+        assert_eq!(Duration::from_millis(250), policy.delay(0, &error));
+        assert_eq!(Duration::from_millis(1000), policy.delay(2, &error));
+
+        let policy = RetryPolicy {
+            jitter: 1.0,
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            assert!(policy.delay(1, &error) <= Duration::from_millis(500));
+        }
+
+        let error = Error::from_status(
+            reqwest::StatusCode::TOO_MANY_REQUESTS,
+            Some(Duration::from_secs(2)),
+            ApiError::default(),
+        );
+        assert_eq!(Duration::from_secs(2), policy.delay(0, &error));
+    }
+
+    #[tokio::test]
+    async fn test_retry_get() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let path = format!("/v3/accounts/{}/instruments", ACCOUNT_ID);
+
+        let unavailable = server
+            .mock("GET", path.as_str())
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", path.as_str())
+            .with_status(200)
+            .with_body(format!(r#"{{"instruments": {}}}"#, instruments_json()))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        )
+        .with_retry_policy(retry_policy(retries.clone()));
+
+        assert!(client.instruments().await.is_ok());
+        assert_eq!(1, retries.load(Ordering::SeqCst));
+
+        unavailable.assert();
+        ok.assert();
+    }
+
+    #[tokio::test]
+    async fn test_retry_get_exhausted() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/instruments", ACCOUNT_ID).as_str(),
+            )
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        )
+        .with_retry_policy(retry_policy(retries.clone()));
+
+        let err = client.instruments().await;
+        assert!(err.is_err_and(|err| matches!(err, Error::Server { .. })));
+        assert_eq!(2, retries.load(Ordering::SeqCst));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_retry_get_not_transient() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/instruments", ACCOUNT_ID).as_str(),
+            )
+            .with_status(401)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        )
+        .with_retry_policy(retry_policy(retries.clone()));
+
+        let err = client.instruments().await;
+        assert!(err.is_err_and(|err| matches!(err, Error::Unauthorized(_))));
+        assert_eq!(0, retries.load(Ordering::SeqCst));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_retry_order_requires_opt_in_and_client_id() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "POST",
+                format!("/v3/accounts/{}/orders", ACCOUNT_ID).as_str(),
+            )
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let policy = retry_policy(retries.clone());
+        let not_opted_in = client.clone().with_retry_policy(policy.clone());
+        assert!(not_opted_in
+            .create_order(&market_order(Some("my-order")))
+            .await
+            .is_err());
+
+        let opted_in = client.with_retry_policy(RetryPolicy {
+            retry_orders: true,
+            ..policy
+        });
+        assert!(opted_in.create_order(&market_order(None)).await.is_err());
+        assert_eq!(0, retries.load(Ordering::SeqCst));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_retry_order_with_client_id() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+        let path = format!("/v3/accounts/{}/orders", ACCOUNT_ID);
+
+        let unavailable = server
+            .mock("POST", path.as_str())
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let created = server
+            .mock("POST", path.as_str())
+            .with_status(201)
+            .with_body(create_order_response_json())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let not_found = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/orders/@my-order", ACCOUNT_ID).as_str(),
+            )
+            .with_status(404)
+            .with_body(r#"{"errorMessage": "Order not found"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        )
+        .with_retry_policy(RetryPolicy {
+            retry_orders: true,
+            ..retry_policy(retries.clone())
+        });
+
+        assert!(client
+            .create_order(&market_order(Some("my-order")))
+            .await
+            .is_ok());
+        assert_eq!(1, retries.load(Ordering::SeqCst));
+
+        unavailable.assert();
+        not_found.assert();
+        created.assert();
+    }
+
+    #[tokio::test]
+    async fn test_retry_order_already_received() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        // The market order filled before the connection failed.
+        let unavailable = server
+            .mock(
+                "POST",
+                format!("/v3/accounts/{}/orders", ACCOUNT_ID).as_str(),
+            )
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let found = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/orders/@my-order", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body("{}")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        )
+        .with_retry_policy(RetryPolicy {
+            retry_orders: true,
+            ..retry_policy(retries.clone())
+        });
+
+        let err = client.create_order(&market_order(Some("my-order"))).await;
+        assert!(err.is_err_and(|err| matches!(err, Error::Server { .. })));
+        assert_eq!(0, retries.load(Ordering::SeqCst));
+
+        unavailable.assert();
+        found.assert();
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use futures::{stream::BoxStream, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+
+struct Lines<S> {
+    body: S,
+    buf: Vec<u8>,
+    heartbeat_timeout: Duration,
+    done: bool,
+}
+
+pub(crate) fn json_lines<T>(
+    resp: reqwest::Response,
+    heartbeat_timeout: Duration,
+) -> BoxStream<'static, Result<T, Error>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let lines = Lines {
+        body: resp.bytes_stream(),
+        buf: Vec::new(),
+        heartbeat_timeout,
+        done: false,
+    };
+
+    futures::stream::unfold(lines, next_message).boxed()
+}
+
+async fn next_message<S, B, T>(mut lines: Lines<S>) -> Option<(Result<T, Error>, Lines<S>)>
+where
+    S: Stream<Item = reqwest::Result<B>> + Unpin,
+    B: AsRef<[u8]>,
+    T: DeserializeOwned,
+{
+    loop {
+        if lines.done {
+            return None;
+        }
+
+        if let Some(pos) = lines.buf.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = lines.buf.drain(..=pos).collect();
+            if line.trim_ascii().is_empty() {
+                continue;
+            }
+
+            return Some((parse(&line), lines));
+        }
+
+        match tokio::time::timeout(lines.heartbeat_timeout, lines.body.next()).await {
+            Ok(Some(Ok(chunk))) => lines.buf.extend_from_slice(chunk.as_ref()),
+            Ok(Some(Err(err))) => {
+                lines.done = true;
+                return Some((Err(Error::Request(err)), lines));
+            }
+            Ok(None) => {
+                lines.done = true;
+                let rest = std::mem::take(&mut lines.buf);
+                if rest.trim_ascii().is_empty() {
+                    return None;
+                }
+
+                return Some((parse(&rest), lines));
+            }
+            Err(_) => {
+                lines.done = true;
+                let timeout = lines.heartbeat_timeout;
+                return Some((Err(Error::HeartbeatTimeout(timeout)), lines));
+            }
+        }
+    }
+}
+
+fn parse<T: DeserializeOwned>(line: &[u8]) -> Result<T, Error> {
+    serde_json::from_slice(line.trim_ascii()).map_err(Error::StreamDeserialize)
+}
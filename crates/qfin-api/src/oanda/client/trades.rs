@@ -0,0 +1,375 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    oanda::{
+        client::path_segment, Client, CloseTradeResponse, CloseTradeUnits,
+        SetTradeDependentOrdersResponse, Trade, TradeDependentOrders, TradeSpecifier, TradesParams,
+    },
+    Error,
+};
+
+#[derive(Serialize)]
+struct CloseTradeBody {
+    units: CloseTradeUnits,
+}
+
+#[derive(Deserialize)]
+struct TradesData {
+    trades: Vec<Trade>,
+}
+
+#[derive(Deserialize)]
+struct TradeData {
+    trade: Trade,
+}
+
+pub(super) async fn trades(client: &Client, params: &TradesParams) -> Result<Vec<Trade>, Error> {
+    let path = format!("/v3/accounts/{}/trades", client.account_id);
+    let data: TradesData = client.get(&path, params).await?;
+
+    Ok(data.trades)
+}
+
+pub(super) async fn open_trades(client: &Client) -> Result<Vec<Trade>, Error> {
+    let path = format!("/v3/accounts/{}/openTrades", client.account_id);
+    let data: TradesData = client.get(&path, &()).await?;
+
+    Ok(data.trades)
+}
+
+pub(super) async fn trade(client: &Client, specifier: &TradeSpecifier) -> Result<Trade, Error> {
+    let path = format!(
+        "/v3/accounts/{}/trades/{}",
+        client.account_id,
+        path_segment(specifier)
+    );
+    let data: TradeData = client.get(&path, &()).await?;
+
+    Ok(data.trade)
+}
+
+pub(super) async fn close_trade(
+    client: &Client,
+    specifier: &TradeSpecifier,
+    units: CloseTradeUnits,
+) -> Result<CloseTradeResponse, Error> {
+    let url = format!(
+        "{}/v3/accounts/{}/trades/{}/close",
+        client.url,
+        client.account_id,
+        path_segment(specifier)
+    );
+    let body = CloseTradeBody { units };
+
+    client.submit(client.client.put(url).json(&body)).await
+}
+
+pub(super) async fn set_trade_dependent_orders(
+    client: &Client,
+    specifier: &TradeSpecifier,
+    orders: &TradeDependentOrders,
+) -> Result<SetTradeDependentOrdersResponse, Error> {
+    let url = format!(
+        "{}/v3/accounts/{}/trades/{}/orders",
+        client.url,
+        client.account_id,
+        path_segment(specifier)
+    );
+
+    client.submit(client.client.put(url).json(orders)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::json;
+
+    use crate::{
+        number::number,
+        oanda::{
+            trade::fixtures::{
+                close_trade_response_json, set_trade_dependent_orders_response_json, trades_json,
+            },
+            Api, Client, CloseTradeUnits, StopLossDetails, TradeDependentOrders, TradeSpecifier,
+            TradeState, TradeStateFilter, TradesParams, Transaction, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    #[tokio::test]
+    async fn test_trades() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/trades", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("ids".into(), "6369,6370".into()),
+                Matcher::UrlEncoded("state".into(), "CLOSE_WHEN_TRADEABLE".into()),
+            ]))
+            .with_status(200)
+            .with_body(format!(r#"{{"trades": {}}}"#, trades_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = TradesParams {
+            ids: vec!["6369".to_string(), "6370".to_string()],
+            state: Some(TradeStateFilter::CloseWhenTradeable),
+            ..Default::default()
+        };
+
+        let trades = client.trades(&params).await.unwrap();
+
+        assert_eq!(1, trades.len());
+        assert_eq!(TradeState::Open, trades[0].state);
+        assert_eq!(Some(number("0.015")), trades[0].unrealized_pl);
+        assert_eq!(Some(number("2.1683")), trades[0].margin_used);
+        assert_eq!(
+            "6371",
+            trades[0].orders.take_profit_order.as_ref().unwrap().id
+        );
+        assert_eq!(
+            Some(number("1.07902")),
+            trades[0]
+                .orders
+                .trailing_stop_loss_order
+                .as_ref()
+                .unwrap()
+                .trailing_stop_value
+        );
+        assert!(trades[0].orders.stop_loss_order.is_none());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_open_trades() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/openTrades", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(format!(r#"{{"trades": {}}}"#, trades_json()))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let trades = client.open_trades().await.unwrap();
+        assert_eq!(1, trades.len());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_trade() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let trades: serde_json::Value = serde_json::from_str(&trades_json()).unwrap();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/trades/@my-trade", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({ "trade": trades[0] }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let trade = client
+            .trade(&TradeSpecifier::ClientId("my-trade".to_string()))
+            .await
+            .unwrap();
+        assert_eq!("6369", trade.id);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_trade_by_client_id_is_percent_encoded() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let trades: serde_json::Value = serde_json::from_str(&trades_json()).unwrap();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/trades/@my%2Ftrade%3F", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({ "trade": trades[0] }).to_string())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let trade = client
+            .trade(&TradeSpecifier::ClientId("my/trade?".to_string()))
+            .await
+            .unwrap();
+        assert_eq!("6369", trade.id);
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_trade_not_found_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/trades/1", ACCOUNT_ID).as_str(),
+            )
+            .with_status(404)
+            .with_body(r#"{"errorMessage": "The Trade specified does not exist"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let err = client.trade(&TradeSpecifier::Id("1".to_string())).await;
+        assert!(err.is_err_and(|err| matches!(err, Error::NotFound(_))))
+    }
+
+    #[tokio::test]
+    async fn test_close_trade() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "PUT",
+                format!("/v3/accounts/{}/trades/6369/close", ACCOUNT_ID).as_str(),
+            )
+            .match_body(Matcher::Json(json!({ "units": "50" })))
+            .with_status(200)
+            .with_body(close_trade_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let resp = client
+            .close_trade(
+                &TradeSpecifier::Id("6369".to_string()),
+                CloseTradeUnits::Units(number("50")),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            resp.order_create_transaction,
+            Transaction::MarketOrder(ref transaction)
+                if transaction.trade_close.as_ref().is_some_and(|close| close.trade_id == "6369")
+        ));
+        let fill = resp.order_fill_transaction.unwrap();
+        assert_eq!(
+            Some(number("0.024")),
+            fill.trade_reduced.unwrap().realized_pl
+        );
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_set_trade_dependent_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "PUT",
+                format!("/v3/accounts/{}/trades/6369/orders", ACCOUNT_ID).as_str(),
+            )
+            .match_body(Matcher::Json(json!({
+                "takeProfit": null,
+                "stopLoss": {
+                    "price": "1.07"
+                }
+            })))
+            .with_status(200)
+            .with_body(set_trade_dependent_orders_response_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let orders = TradeDependentOrders {
+            take_profit: Some(None),
+            stop_loss: Some(Some(StopLossDetails {
+                price: Some(number("1.07")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        let resp = client
+            .set_trade_dependent_orders(&TradeSpecifier::Id("6369".to_string()), &orders)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            "6371",
+            resp.take_profit_order_cancel_transaction.unwrap().order_id
+        );
+        assert!(matches!(
+            resp.stop_loss_order_transaction,
+            Some(Transaction::StopLossOrder(_))
+        ));
+        assert!(resp.trailing_stop_loss_order_transaction.is_none());
+
+        mock.assert();
+    }
+}
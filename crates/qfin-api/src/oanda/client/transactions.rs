@@ -0,0 +1,332 @@
+use std::time::Duration;
+
+use futures::stream::BoxStream;
+use serde::Deserialize;
+
+use crate::{
+    oanda::{
+        client::stream::json_lines, Client, Transaction, TransactionPages,
+        TransactionStreamMessage, TransactionsIdRangeParams, TransactionsParams,
+        TransactionsSinceIdParams,
+    },
+    Error,
+};
+
+#[derive(Deserialize)]
+struct TransactionsData {
+    transactions: Vec<Transaction>,
+}
+
+#[derive(Deserialize)]
+struct TransactionData {
+    transaction: Transaction,
+}
+
+pub(super) async fn transactions(
+    client: &Client,
+    params: &TransactionsParams,
+) -> Result<TransactionPages, Error> {
+    let path = format!("/v3/accounts/{}/transactions", client.account_id);
+    client.get(&path, params).await
+}
+
+pub(super) async fn transaction(client: &Client, id: &str) -> Result<Transaction, Error> {
+    let path = format!("/v3/accounts/{}/transactions/{}", client.account_id, id);
+    let data: TransactionData = client.get(&path, &()).await?;
+
+    Ok(data.transaction)
+}
+
+pub(super) async fn transactions_id_range(
+    client: &Client,
+    params: &TransactionsIdRangeParams,
+) -> Result<Vec<Transaction>, Error> {
+    let path = format!("/v3/accounts/{}/transactions/idrange", client.account_id);
+    let data: TransactionsData = client.get(&path, params).await?;
+
+    Ok(data.transactions)
+}
+
+pub(super) async fn transactions_since_id(
+    client: &Client,
+    params: &TransactionsSinceIdParams,
+) -> Result<Vec<Transaction>, Error> {
+    let path = format!("/v3/accounts/{}/transactions/sinceid", client.account_id);
+    let data: TransactionsData = client.get(&path, params).await?;
+
+    Ok(data.transactions)
+}
+
+impl Client {
+    pub async fn transactions_stream(
+        &self,
+        heartbeat_timeout: Duration,
+    ) -> Result<BoxStream<'static, Result<TransactionStreamMessage, Error>>, Error> {
+        let path = format!("/v3/accounts/{}/transactions/stream", self.account_id);
+        let resp = self.get_stream(&path, &()).await?;
+
+        Ok(json_lines(resp, heartbeat_timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use mockito::Matcher;
+
+    use crate::{
+        number::number,
+        oanda::{
+            transaction::fixtures::{transaction_pages_json, transactions_json},
+            Api, Client, Transaction, TransactionFilter, TransactionStreamMessage,
+            TransactionsIdRangeParams, TransactionsParams, TransactionsSinceIdParams, Url,
+        },
+        Error,
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    #[tokio::test]
+    async fn test_transactions() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/transactions", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("pageSize".into(), "100".into()),
+                Matcher::UrlEncoded("type".into(), "ORDER_FILL,DAILY_FINANCING".into()),
+            ]))
+            .with_status(200)
+            .with_body(transaction_pages_json())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = TransactionsParams {
+            page_size: Some(100),
+            types: vec![
+                TransactionFilter::OrderFill,
+                TransactionFilter::DailyFinancing,
+            ],
+            ..Default::default()
+        };
+
+        let pages = client.transactions(&params).await.unwrap();
+
+        assert_eq!(2, pages.count);
+        assert_eq!(1, pages.pages.len());
+        assert_eq!(
+            vec![
+                TransactionFilter::OrderFill,
+                TransactionFilter::DailyFinancing
+            ],
+            pages.types
+        );
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_transaction() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let transactions: serde_json::Value = serde_json::from_str(&transactions_json()).unwrap();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/transactions/6380", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "transaction": transactions[2],
+                    "lastTransactionID": "6383"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let transaction = client.transaction("6380").await.unwrap();
+        assert!(matches!(
+            transaction,
+            Transaction::DailyFinancing(ref transaction) if transaction.financing == number("-0.0012")
+        ));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_transactions_id_range() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/transactions/idrange", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("from".into(), "1".into()),
+                Matcher::UrlEncoded("to".into(), "6382".into()),
+            ]))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"transactions": {}, "lastTransactionID": "6383"}}"#,
+                transactions_json()
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = TransactionsIdRangeParams {
+            from: "1".to_string(),
+            to: "6382".to_string(),
+            ..Default::default()
+        };
+
+        let transactions = client.transactions_id_range(&params).await.unwrap();
+        assert_eq!(5, transactions.len());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_transactions_since_id() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/transactions/sinceid", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".into(), "6379".into()),
+                Matcher::UrlEncoded("type".into(), "FUNDING".into()),
+            ]))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"transactions": {}, "lastTransactionID": "6383"}}"#,
+                transactions_json()
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let params = TransactionsSinceIdParams {
+            id: "6379".to_string(),
+            types: vec![TransactionFilter::Funding],
+        };
+
+        assert!(client.transactions_since_id(&params).await.is_ok());
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_transactions_stream() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/transactions/stream", ACCOUNT_ID).as_str(),
+            )
+            .with_status(200)
+            .with_body(concat!(
+                r#"{"type":"HEARTBEAT","lastTransactionID":"6383","time":"2024-01-02T23:00:00.000000000Z"}"#,
+                "\n",
+                r#"{"type":"MARGIN_CALL_EXIT","id":"6384","accountID":"account_id","userID":1234567,"batchID":"6384","time":"2024-01-02T23:00:01.000000000Z"}"#,
+                "\n",
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let messages: Vec<_> = client
+            .transactions_stream(Duration::from_secs(10))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(2, messages.len());
+        assert!(matches!(
+            messages[0],
+            Ok(TransactionStreamMessage::Heartbeat(_))
+        ));
+        assert!(matches!(
+            messages[1],
+            Ok(TransactionStreamMessage::Transaction(ref transaction))
+                if matches!(**transaction, Transaction::MarginCallExit(_))
+        ));
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_transaction_not_found_error() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/transactions/1", ACCOUNT_ID).as_str(),
+            )
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let err = client.transaction("1").await;
+        assert!(err.is_err_and(|err| matches!(err, Error::NotFound(_))))
+    }
+}
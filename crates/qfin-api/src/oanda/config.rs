@@ -0,0 +1,557 @@
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    oanda::{client::ClientBuilder, Client, Url},
+    Error,
+};
+
+/// The `~/.v20.conf` file used by OANDA's v20 command line tools and samples.
+///
+/// The file is written as YAML but only ever holds flat `key: value` pairs
+/// and the `accounts` list, which is all [`FromStr`] accepts.
+#[derive(Clone, PartialEq)]
+pub struct V20Config {
+    pub hostname: String,
+    pub streaming_hostname: Option<String>,
+    pub port: u16,
+    pub ssl: bool,
+    pub token: String,
+    pub username: Option<String>,
+    pub accounts: Vec<String>,
+    pub active_account: Option<String>,
+    pub datetime_format: Option<String>,
+}
+
+impl V20Config {
+    /// Returns `~/.v20.conf`, or `None` if the home directory is unknown.
+    pub fn default_path() -> Option<PathBuf> {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join(".v20.conf"))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        read_config(path.as_ref())?.parse()
+    }
+
+    /// Streams go to `streaming_hostname`, or to the stream host matching a
+    /// practice or live `hostname` when it is missing. Other hosts are assumed
+    /// to serve both.
+    pub fn url(&self) -> Url {
+        let api = self.host_url(&self.hostname);
+        let streaming_hostname = self
+            .streaming_hostname
+            .as_deref()
+            .filter(|streaming_hostname| *streaming_hostname != self.hostname)
+            .or_else(|| oanda_streaming_hostname(&self.hostname));
+
+        match streaming_hostname {
+            Some(streaming_hostname) => Url::Hosts {
+                api,
+                stream: self.host_url(streaming_hostname),
+            },
+            None => Url::Custom(api),
+        }
+    }
+
+    /// Returns a builder for the active account, or the only account when
+    /// none is active.
+    pub fn client_builder(&self) -> Result<ClientBuilder, Error> {
+        let account_id = match (&self.active_account, self.accounts.as_slice()) {
+            (Some(account_id), _) => account_id,
+            (None, [account_id]) => account_id,
+            _ => return Err(Error::Config("no active account".to_string())),
+        };
+
+        let mut builder = ClientBuilder::new()
+            .account_id(account_id)
+            .auth_token(&self.token)
+            .url(self.url());
+        if let Some(format) = &self.datetime_format {
+            builder = builder.datetime_format(format.parse()?);
+        }
+
+        Ok(builder)
+    }
+
+    pub fn client(&self) -> Result<Client, Error> {
+        self.client_builder()?.build()
+    }
+
+    fn host_url(&self, hostname: &str) -> String {
+        let scheme = if self.ssl { "https" } else { "http" };
+        match (self.ssl, self.port) {
+            (true, 443) | (false, 80) => format!("{scheme}://{hostname}"),
+            (_, port) => format!("{scheme}://{hostname}:{port}"),
+        }
+    }
+}
+
+impl FromStr for V20Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = BTreeMap::new();
+        let mut accounts = Vec::new();
+        let mut in_accounts = false;
+
+        // Errors name the line rather than quoting it, since it may hold the
+        // token.
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(item) = line.strip_prefix('-') {
+                if !in_accounts {
+                    return Err(invalid_v20(line_number, "unexpected list item"));
+                }
+                accounts.push(unquote(item.trim()).to_string());
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| invalid_v20(line_number, "expected `key: value`"))?;
+            let (key, value) = (key.trim(), unquote(value.trim()));
+
+            in_accounts = key == "accounts";
+            if in_accounts {
+                accounts.extend(
+                    flow_list(value)
+                        .ok_or_else(|| invalid_v20(line_number, "expected a list of accounts"))?,
+                );
+            } else if !value.is_empty() {
+                values.insert(key, value.to_string());
+            }
+        }
+
+        let required = |values: &mut BTreeMap<&str, String>, key| {
+            values
+                .remove(key)
+                .ok_or_else(|| Error::Config(format!("invalid v20 config: {key} is not set")))
+        };
+
+        Ok(V20Config {
+            hostname: required(&mut values, "hostname")?,
+            streaming_hostname: values.remove("streaming_hostname"),
+            port: match values.remove("port") {
+                Some(port) => port
+                    .parse()
+                    .map_err(|_| Error::Config(format!("invalid v20 config: port {port}")))?,
+                None => 443,
+            },
+            ssl: match values.remove("ssl").as_deref() {
+                Some("true" | "True" | "TRUE") => true,
+                Some("false" | "False" | "FALSE") => false,
+                Some(ssl) => return Err(Error::Config(format!("invalid v20 config: ssl {ssl}"))),
+                None => true,
+            },
+            token: required(&mut values, "token")?,
+            username: values.remove("username"),
+            accounts,
+            active_account: values.remove("active_account"),
+            datetime_format: values.remove("datetime_format"),
+        })
+    }
+}
+
+// The token is a bearer credential, so it is never printed.
+impl fmt::Debug for V20Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("V20Config")
+            .field("hostname", &self.hostname)
+            .field("streaming_hostname", &self.streaming_hostname)
+            .field("port", &self.port)
+            .field("ssl", &self.ssl)
+            .field("token", &"<redacted>")
+            .field("username", &self.username)
+            .field("accounts", &self.accounts)
+            .field("active_account", &self.active_account)
+            .field("datetime_format", &self.datetime_format)
+            .finish()
+    }
+}
+
+fn oanda_streaming_hostname(hostname: &str) -> Option<&'static str> {
+    match hostname {
+        "api-fxpractice.oanda.com" => Some("stream-fxpractice.oanda.com"),
+        "api-fxtrade.oanda.com" => Some("stream-fxtrade.oanda.com"),
+        _ => None,
+    }
+}
+
+fn invalid_v20(line_number: usize, message: &str) -> Error {
+    Error::Config(format!("invalid v20 config: line {line_number}: {message}"))
+}
+
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| value.strip_prefix(*quote)?.strip_suffix(*quote))
+        .unwrap_or(value)
+}
+
+/// Parses an inline `[a, b]` list. An empty value starts a block list.
+fn flow_list(value: &str) -> Option<Vec<String>> {
+    if value.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let items = value.strip_prefix('[')?.strip_suffix(']')?;
+    Some(
+        items
+            .split(',')
+            .map(|item| unquote(item.trim()))
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// A TOML file of named profiles, e.g.
+///
+/// ```toml
+/// default = "practice"
+///
+/// [profiles.practice]
+/// account_id = "101-004-1234567-001"
+/// token = "..."
+///
+/// [profiles.desk-a]
+/// environment = "live"
+/// account_id = "001-001-1234567-002"
+/// token = "..."
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profiles {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub account_id: String,
+    pub token: String,
+    /// `live`, `practice` (the default) or a custom URL, as in `OANDA_ENV`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_url: Option<String>,
+}
+
+impl Profiles {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        read_config(path.as_ref())?.parse()
+    }
+
+    /// Returns the profile called `name`, or the default profile when `name`
+    /// is `None`.
+    pub fn profile(&self, name: Option<&str>) -> Result<&Profile, Error> {
+        let name = name
+            .or(self.default.as_deref())
+            .ok_or_else(|| Error::Config("no default profile".to_string()))?;
+
+        self.profiles
+            .get(name)
+            .ok_or_else(|| Error::Config(format!("profile not found: {name}")))
+    }
+
+    pub fn client(&self, name: Option<&str>) -> Result<Client, Error> {
+        self.profile(name)?.client()
+    }
+}
+
+impl FromStr for Profiles {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|err| Error::Config(format!("invalid profiles: {err}")))
+    }
+}
+
+impl Profile {
+    pub fn url(&self) -> Result<Url, Error> {
+        self.environment
+            .as_deref()
+            .map_or(Ok(Url::Practice), str::parse)
+    }
+
+    pub fn client_builder(&self) -> Result<ClientBuilder, Error> {
+        let mut builder = ClientBuilder::new()
+            .account_id(&self.account_id)
+            .auth_token(&self.token)
+            .url(self.url()?);
+        if let Some(stream_url) = &self.stream_url {
+            builder = builder.stream_url(stream_url);
+        }
+
+        Ok(builder)
+    }
+
+    pub fn client(&self) -> Result<Client, Error> {
+        self.client_builder()?.build()
+    }
+}
+
+impl fmt::Debug for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Profile")
+            .field("account_id", &self.account_id)
+            .field("token", &"<redacted>")
+            .field("environment", &self.environment)
+            .field("stream_url", &self.stream_url)
+            .finish()
+    }
+}
+
+fn read_config(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path)
+        .map_err(|err| Error::Config(format!("error reading {}: {err}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use crate::{
+        oanda::{Profiles, Url, V20Config},
+        Error,
+    };
+
+    fn v20_config_yaml() -> String {
+        r#"
+hostname: api-fxpractice.oanda.com
+streaming_hostname: stream-fxpractice.oanda.com
+port: 443
+ssl: true
+token: auth_token
+username: trader
+accounts:
+- 101-004-1234567-001
+- 101-004-1234567-002
+active_account: 101-004-1234567-002
+datetime_format: RFC3339
+"#
+        .to_owned()
+    }
+
+    fn profiles_toml() -> String {
+        r#"
+default = "practice"
+
+[profiles.practice]
+account_id = "101-004-1234567-001"
+token = "practice_token"
+
+[profiles.desk-a]
+environment = "live"
+account_id = "001-001-1234567-002"
+token = "live_token"
+
+[profiles.local]
+environment = "http://localhost:8080"
+stream_url = "http://localhost:8081"
+account_id = "account_id"
+token = "local_token"
+"#
+        .to_owned()
+    }
+
+    #[test]
+    fn test_v20_config_from_str() {
+        let config: V20Config = v20_config_yaml().parse().unwrap();
+
+        assert_eq!("api-fxpractice.oanda.com", config.hostname);
+        assert_eq!(2, config.accounts.len());
+        assert_eq!(
+            Some("101-004-1234567-002"),
+            config.active_account.as_deref()
+        );
+        assert_eq!(
+            Url::Hosts {
+                api: "https://api-fxpractice.oanda.com".to_string(),
+                stream: "https://stream-fxpractice.oanda.com".to_string(),
+            },
+            config.url()
+        );
+        assert!(config.client().is_ok());
+    }
+
+    #[test]
+    fn test_v20_config_url_known_hosts() {
+        for (hostname, stream) in [
+            (
+                "api-fxpractice.oanda.com",
+                "https://stream-fxpractice.oanda.com",
+            ),
+            ("api-fxtrade.oanda.com", "https://stream-fxtrade.oanda.com"),
+        ] {
+            let config: V20Config = format!("hostname: {hostname}\ntoken: t").parse().unwrap();
+            assert_eq!(
+                Url::Hosts {
+                    api: format!("https://{hostname}"),
+                    stream: stream.to_string(),
+                },
+                config.url()
+            );
+
+            let config = V20Config {
+                streaming_hostname: Some(hostname.to_string()),
+                ..config
+            };
+            assert_eq!(stream, config.url().stream_url());
+        }
+    }
+
+    #[test]
+    fn test_v20_config_url() {
+        let config: V20Config = "hostname: localhost\nport: 8080\nssl: false\ntoken: t"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            Url::Custom("http://localhost:8080".to_string()),
+            config.url()
+        );
+    }
+
+    #[test]
+    fn test_v20_config_flat_yaml() {
+        let config: V20Config = r#"
+# written by v20-configure
+hostname: "localhost"
+token: 'auth_token'
+username:
+accounts: [101-004-1234567-001, "101-004-1234567-002"]
+"#
+        .parse()
+        .unwrap();
+
+        assert_eq!("localhost", config.hostname);
+        assert_eq!("auth_token", config.token);
+        assert_eq!(None, config.username);
+        assert_eq!(
+            vec!["101-004-1234567-001", "101-004-1234567-002"],
+            config.accounts
+        );
+        assert_eq!(443, config.port);
+        assert!(config.ssl);
+
+        let config: V20Config = "hostname: localhost\ntoken: t\naccounts:\n  - 1\n  - 2"
+            .parse()
+            .unwrap();
+        assert_eq!(vec!["1", "2"], config.accounts);
+    }
+
+    #[test]
+    fn test_v20_config_invalid() {
+        for (config, message) in [
+            ("token: t", "hostname is not set"),
+            ("hostname: localhost", "token is not set"),
+            ("hostname: localhost\ntoken: t\nport: https", "port"),
+            ("hostname: localhost\ntoken: t\nssl: maybe", "ssl"),
+            ("hostname: localhost\ntoken: t\n- 1", "line 3"),
+            ("hostname: localhost\nsecret_token", "line 2"),
+            ("hostname: localhost\ntoken: t\naccounts: 1", "line 3"),
+        ] {
+            let err = config.parse::<V20Config>().unwrap_err();
+            assert!(matches!(err, Error::Config(_)));
+            assert!(err.to_string().contains(message), "{err}");
+            assert!(!err.to_string().contains("secret_token"));
+        }
+    }
+
+    #[test]
+    fn test_config_debug_redacts_token() {
+        let config: V20Config = v20_config_yaml().parse().unwrap();
+        let debug = format!("{config:?}");
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("auth_token"));
+
+        let profiles: Profiles = profiles_toml().parse().unwrap();
+        let debug = format!("{profiles:?}");
+        assert!(debug.contains("desk-a"));
+        assert!(!debug.contains("practice_token"));
+        assert!(!debug.contains("live_token"));
+    }
+
+    #[test]
+    fn test_v20_config_no_active_account() {
+        let config = V20Config {
+            active_account: None,
+            ..v20_config_yaml().parse().unwrap()
+        };
+        assert!(config
+            .client_builder()
+            .is_err_and(|err| matches!(err, Error::Config(_))));
+
+        let config = V20Config {
+            accounts: vec!["101-004-1234567-001".to_string()],
+            ..config
+        };
+        assert!(config.client_builder().is_ok());
+    }
+
+    #[test]
+    fn test_v20_config_load() {
+        let path = env::temp_dir().join(format!("qfin-v20-{}.conf", std::process::id()));
+        fs::write(&path, v20_config_yaml()).unwrap();
+
+        let config = V20Config::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            v20_config_yaml().parse::<V20Config>().unwrap(),
+            config.unwrap()
+        );
+        assert!(V20Config::load(&path).is_err_and(|err| matches!(err, Error::Config(_))));
+    }
+
+    #[test]
+    fn test_profiles_from_str() {
+        let profiles: Profiles = profiles_toml().parse().unwrap();
+
+        let profile = profiles.profile(None).unwrap();
+        assert_eq!("101-004-1234567-001", profile.account_id);
+        assert_eq!(Url::Practice, profile.url().unwrap());
+
+        let profile = profiles.profile(Some("desk-a")).unwrap();
+        assert_eq!("live_token", profile.token);
+        assert_eq!(Url::Live, profile.url().unwrap());
+
+        let profile = profiles.profile(Some("local")).unwrap();
+        assert_eq!(
+            Url::Hosts {
+                api: "http://localhost:8080".to_string(),
+                stream: "http://localhost:8081".to_string(),
+            },
+            profile.client_builder().unwrap().resolved_url()
+        );
+        assert!(profiles.client(Some("local")).is_ok());
+        assert!(profiles
+            .profile(Some("desk-b"))
+            .is_err_and(|err| err.to_string().contains("desk-b")));
+    }
+
+    #[test]
+    fn test_profiles_invalid() {
+        assert!("profiles = 1"
+            .parse::<Profiles>()
+            .is_err_and(|err| matches!(err, Error::Config(_))));
+
+        let profiles = Profiles::default();
+        assert!(profiles
+            .profile(None)
+            .is_err_and(|err| matches!(err, Error::Config(_))));
+    }
+}
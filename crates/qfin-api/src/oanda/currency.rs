@@ -0,0 +1,210 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+use crate::oanda::{Instrument, InstrumentType};
+
+/// A three-letter currency code such as `USD`, `CNH` or `XAU`.
+///
+/// Only the shape is checked: any three uppercase ASCII letters parse, whether
+/// or not ISO 4217 assigns them, so commodity codes such as `BCO` are accepted
+/// too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CurrencyCode([u8; 3]);
+
+impl CurrencyCode {
+    pub fn as_str(&self) -> &str {
+        // Only ever constructed from ASCII uppercase letters.
+        std::str::from_utf8(&self.0).unwrap()
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[a, b, c] if [a, b, c].iter().all(u8::is_ascii_uppercase) => {
+                Ok(CurrencyCode([a, b, c]))
+            }
+            _ => Err(ParseCurrencyError::InvalidCurrency(s.to_string())),
+        }
+    }
+}
+
+impl Serialize for CurrencyCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// An OANDA instrument name split at the underscore, e.g. `TRY_JPY` or
+/// `SPX500_USD`. The base is only a currency for currency and metal pairs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InstrumentName {
+    pub base: String,
+    pub quote: CurrencyCode,
+}
+
+impl InstrumentName {
+    pub fn currency_pair(&self) -> Option<CurrencyPair> {
+        Some(CurrencyPair {
+            base: self.base.parse().ok()?,
+            quote: self.quote,
+        })
+    }
+}
+
+impl fmt::Display for InstrumentName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.base, self.quote)
+    }
+}
+
+impl FromStr for InstrumentName {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseCurrencyError::InvalidInstrumentName(s.to_string());
+
+        let (base, quote) = s.split_once('_').ok_or_else(invalid)?;
+        if base.is_empty() || base.contains('_') {
+            return Err(invalid());
+        }
+
+        Ok(InstrumentName {
+            base: base.to_string(),
+            quote: quote.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CurrencyPair {
+    pub base: CurrencyCode,
+    pub quote: CurrencyCode,
+}
+
+impl fmt::Display for CurrencyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.base, self.quote)
+    }
+}
+
+impl FromStr for CurrencyPair {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<InstrumentName>()?
+            .currency_pair()
+            .ok_or_else(|| ParseCurrencyError::InvalidCurrencyPair(s.to_string()))
+    }
+}
+
+impl Instrument {
+    pub fn instrument_name(&self) -> Result<InstrumentName, ParseCurrencyError> {
+        self.name.parse()
+    }
+
+    /// Returns the base and quote currencies for currency and metal
+    /// instruments. CFDs such as `BCO_USD` have no base currency.
+    pub fn currency_pair(&self) -> Option<CurrencyPair> {
+        match self.instrument_type {
+            InstrumentType::Currency | InstrumentType::Metal => {
+                self.instrument_name().ok()?.currency_pair()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseCurrencyError {
+    #[error("invalid currency code: {0}")]
+    InvalidCurrency(String),
+
+    #[error("invalid instrument name: {0}")]
+    InvalidInstrumentName(String),
+
+    #[error("instrument is not a currency pair: {0}")]
+    InvalidCurrencyPair(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oanda::{
+        instrument::fixtures::instruments, CurrencyCode, CurrencyPair, InstrumentName,
+        ParseCurrencyError,
+    };
+
+    #[test]
+    fn test_currency_from_str() {
+        assert_eq!("USD", "USD".parse::<CurrencyCode>().unwrap().as_str());
+        assert_eq!(
+            Err(ParseCurrencyError::InvalidCurrency("usd".to_string())),
+            "usd".parse::<CurrencyCode>()
+        );
+        assert!("USDT".parse::<CurrencyCode>().is_err());
+
+        // Codes outside ISO 4217 are not rejected.
+        assert_eq!("CNH", "CNH".parse::<CurrencyCode>().unwrap().as_str());
+        assert_eq!("BCO", "BCO".parse::<CurrencyCode>().unwrap().as_str());
+    }
+
+    #[test]
+    fn test_instrument_name_from_str() {
+        let name: InstrumentName = "SPX500_USD".parse().unwrap();
+        assert_eq!("SPX500", name.base);
+        assert_eq!("USD", name.quote.as_str());
+        assert_eq!(None, name.currency_pair());
+        assert_eq!("SPX500_USD", name.to_string());
+
+        assert!("EURUSD".parse::<InstrumentName>().is_err());
+        assert!("_USD".parse::<InstrumentName>().is_err());
+        assert!("EUR_USD_X".parse::<InstrumentName>().is_err());
+    }
+
+    #[test]
+    fn test_currency_pair_from_str() {
+        let pair: CurrencyPair = "TRY_JPY".parse().unwrap();
+        assert_eq!("TRY", pair.base.as_str());
+        assert_eq!("JPY", pair.quote.as_str());
+        assert_eq!("TRY_JPY", pair.to_string());
+
+        assert_eq!(
+            Err(ParseCurrencyError::InvalidCurrencyPair(
+                "DE30_EUR".to_string()
+            )),
+            "DE30_EUR".parse::<CurrencyPair>()
+        );
+    }
+
+    #[test]
+    fn test_instrument_currency_pair() {
+        for instrument in instruments() {
+            let pair = instrument.currency_pair().unwrap();
+            assert_eq!(instrument.name, pair.to_string());
+        }
+    }
+}
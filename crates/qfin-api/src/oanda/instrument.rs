@@ -1,79 +1,413 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize, Serializer};
+use thiserror::Error;
 
-use crate::deserializer::de_string_as_f64;
+use crate::{
+    deserializer::de_string_as_number,
+    macros::string_enum,
+    number::{pow10, round_dp, trunc_dp},
+    serializer::ser_number_as_string,
+    Number,
+};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Instrument {
     pub name: String,
     #[serde(rename = "type")]
-    pub instrument_type: String,
+    pub instrument_type: InstrumentType,
     pub display_name: String,
     pub pip_location: i8,
     pub display_precision: i8,
     pub trade_units_precision: i8,
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub minimum_trade_size: f64,
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub maximum_trailing_stop_distance: f64,
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub minimum_trailing_stop_distance: f64,
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub maximum_position_size: f64,
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub maximum_order_units: f64,
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub margin_rate: f64,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub minimum_trade_size: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub maximum_trailing_stop_distance: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub minimum_trailing_stop_distance: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub maximum_position_size: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub maximum_order_units: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub margin_rate: Number,
     pub guaranteed_stop_loss_order_mode: GuaranteedStopLossOrderModeForInstrument,
     pub tags: Vec<Tag>,
     pub financing: InstrumentFinancing,
 }
 
-#[derive(Debug, Deserialize)]
-pub enum GuaranteedStopLossOrderModeForInstrument {
-    DISABLED,
-    ALLOWED,
-    REQUIRED,
+impl Instrument {
+    pub fn pip_size(&self) -> Number {
+        pow10(self.pip_location.into())
+    }
+
+    pub fn price_to_pips(&self, distance: Number) -> Number {
+        distance * pow10(-i32::from(self.pip_location))
+    }
+
+    pub fn pips_to_price(&self, pips: Number) -> Number {
+        pips / pow10(-i32::from(self.pip_location))
+    }
+
+    pub fn round_price(&self, price: Number) -> Number {
+        round_dp(price, precision(self.display_precision))
+    }
+
+    /// Truncates towards zero so rounding never increases exposure.
+    pub fn round_units(&self, units: Number) -> Number {
+        trunc_dp(units, precision(self.trade_units_precision))
+    }
+
+    pub fn validate_price(&self, price: Number) -> Result<(), InstrumentError> {
+        if self.round_price(price) != price {
+            return Err(InstrumentError::PricePrecision {
+                price,
+                precision: self.display_precision,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_units(&self, units: Number) -> Result<(), InstrumentError> {
+        if self.round_units(units) != units {
+            return Err(InstrumentError::UnitsPrecision {
+                units,
+                precision: self.trade_units_precision,
+            });
+        }
+        if units.abs() < self.minimum_trade_size {
+            return Err(InstrumentError::BelowMinimumTradeSize {
+                units,
+                minimum: self.minimum_trade_size,
+            });
+        }
+        if units.abs() > self.maximum_order_units {
+            return Err(InstrumentError::AboveMaximumOrderUnits {
+                units,
+                maximum: self.maximum_order_units,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_trailing_stop_distance(&self, distance: Number) -> Result<(), InstrumentError> {
+        self.validate_price(distance)?;
+
+        if distance < self.minimum_trailing_stop_distance {
+            return Err(InstrumentError::BelowMinimumTrailingStopDistance {
+                distance,
+                minimum: self.minimum_trailing_stop_distance,
+            });
+        }
+        if distance > self.maximum_trailing_stop_distance {
+            return Err(InstrumentError::AboveMaximumTrailingStopDistance {
+                distance,
+                maximum: self.maximum_trailing_stop_distance,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn precision(places: i8) -> u32 {
+    places.max(0) as u32
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InstrumentError {
+    #[error("price {price} exceeds display precision of {precision} decimal places")]
+    PricePrecision { price: Number, precision: i8 },
+
+    #[error("units {units} exceed trade units precision of {precision} decimal places")]
+    UnitsPrecision { units: Number, precision: i8 },
+
+    #[error("units {units} below minimum trade size of {minimum}")]
+    BelowMinimumTradeSize { units: Number, minimum: Number },
+
+    #[error("units {units} above maximum order units of {maximum}")]
+    AboveMaximumOrderUnits { units: Number, maximum: Number },
+
+    #[error("trailing stop distance {distance} below minimum of {minimum}")]
+    BelowMinimumTrailingStopDistance { distance: Number, minimum: Number },
+
+    #[error("trailing stop distance {distance} above maximum of {maximum}")]
+    AboveMaximumTrailingStopDistance { distance: Number, maximum: Number },
 }
 
-#[derive(Debug, Deserialize)]
+string_enum! {
+    pub enum InstrumentType {
+        Currency = "CURRENCY",
+        Cfd = "CFD",
+        Metal = "METAL",
+    }
+}
+
+string_enum! {
+    pub enum GuaranteedStopLossOrderModeForInstrument {
+        Disabled = "DISABLED",
+        Allowed = "ALLOWED",
+        Required = "REQUIRED",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
     #[serde(rename = "type")]
-    pub tag_type: String,
-    pub name: String,
+    pub tag_type: TagType,
+    pub name: TagName,
+}
+
+string_enum! {
+    pub enum TagType {
+        AssetClass = "ASSET_CLASS",
+        BrainAssetClass = "BRAIN_ASSET_CLASS",
+        KidAssetClass = "KID_ASSET_CLASS",
+    }
+}
+
+string_enum! {
+    pub enum TagName {
+        Currency = "CURRENCY",
+        Fx = "FX",
+        Metal = "METAL",
+        Commodity = "COMMODITY",
+        Index = "INDEX",
+        Bond = "BOND",
+        Equity = "EQUITY",
+        Cfd = "CFD",
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InstrumentFinancing {
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub long_rate: f64,
-    #[serde(deserialize_with = "de_string_as_f64")]
-    pub short_rate: f64,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub long_rate: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub short_rate: Number,
     pub financing_days_of_week: Vec<FinancingDayOfWeek>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FinancingDayOfWeek {
     pub day_of_week: DayOfWeek,
     pub days_charged: i8,
 }
 
-#[derive(Debug, Deserialize)]
-pub enum DayOfWeek {
-    SUNDAY,
-    MONDAY,
-    TUESDAY,
-    WEDNESDAY,
-    THURSDAY,
-    FRIDAY,
-    SATURDAY,
+string_enum! {
+    pub enum DayOfWeek {
+        Sunday = "SUNDAY",
+        Monday = "MONDAY",
+        Tuesday = "TUESDAY",
+        Wednesday = "WEDNESDAY",
+        Thursday = "THURSDAY",
+        Friday = "FRIDAY",
+        Saturday = "SATURDAY",
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandlesParams {
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "ser_price_components"
+    )]
+    pub price: Vec<PriceComponent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<CandlestickGranularity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smooth: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_first: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_alignment: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alignment_timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_alignment: Option<WeeklyAlignment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceComponent {
+    Mid,
+    Bid,
+    Ask,
+}
+
+impl PriceComponent {
+    fn as_char(&self) -> char {
+        match self {
+            PriceComponent::Mid => 'M',
+            PriceComponent::Bid => 'B',
+            PriceComponent::Ask => 'A',
+        }
+    }
+}
+
+fn ser_price_components<S>(components: &[PriceComponent], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let price: String = components.iter().map(PriceComponent::as_char).collect();
+    serializer.serialize_str(&price)
+}
+
+string_enum! {
+    pub enum CandlestickGranularity {
+        S5 = "S5",
+        S10 = "S10",
+        S15 = "S15",
+        S30 = "S30",
+        M1 = "M1",
+        M2 = "M2",
+        M4 = "M4",
+        M5 = "M5",
+        M10 = "M10",
+        M15 = "M15",
+        M30 = "M30",
+        H1 = "H1",
+        H2 = "H2",
+        H3 = "H3",
+        H4 = "H4",
+        H6 = "H6",
+        H8 = "H8",
+        H12 = "H12",
+        D = "D",
+        W = "W",
+        M = "M",
+    }
+}
+
+string_enum! {
+    pub enum WeeklyAlignment {
+        Monday = "Monday",
+        Tuesday = "Tuesday",
+        Wednesday = "Wednesday",
+        Thursday = "Thursday",
+        Friday = "Friday",
+        Saturday = "Saturday",
+        Sunday = "Sunday",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Candlestick {
+    pub time: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid: Option<CandlestickData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ask: Option<CandlestickData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mid: Option<CandlestickData>,
+    pub volume: i64,
+    pub complete: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CandlestickData {
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub o: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub h: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub l: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub c: Number,
+}
+
+/// A snapshot of open orders or positions, bucketed by price.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Book {
+    pub instrument: String,
+    pub time: DateTime<Utc>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub bucket_width: Number,
+    pub buckets: Vec<BookBucket>,
+}
+
+pub type OrderBook = Book;
+pub type PositionBook = Book;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookBucket {
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub long_count_percent: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub short_count_percent: Number,
 }
 
 #[cfg(test)]
-pub mod fixtures {
-    use crate::oanda::Instrument;
+pub(crate) mod fixtures {
+    use crate::oanda::{Candlestick, Instrument};
 
     pub fn instruments() -> Vec<Instrument> {
         let json = instruments_json();
@@ -4099,4 +4433,306 @@ pub mod fixtures {
         "#
         .to_owned()
     }
+
+    pub fn candles() -> Vec<Candlestick> {
+        let json = candles_json();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    pub fn candles_json() -> String {
+        r#"
+        [
+            {
+                "complete": true,
+                "mid": {
+                    "c": "1.08392",
+                    "h": "1.08410",
+                    "l": "1.08366",
+                    "o": "1.08371"
+                },
+                "time": "2024-01-02T10:00:00.000000000Z",
+                "volume": 1221
+            },
+            {
+                "complete": true,
+                "mid": {
+                    "c": "1.08421",
+                    "h": "1.08440",
+                    "l": "1.08380",
+                    "o": "1.08393"
+                },
+                "time": "2024-01-02T10:05:00.000000000Z",
+                "volume": 1064
+            },
+            {
+                "complete": false,
+                "mid": {
+                    "c": "1.08405",
+                    "h": "1.08428",
+                    "l": "1.08397",
+                    "o": "1.08420"
+                },
+                "time": "2024-01-02T10:10:00.000000000Z",
+                "volume": 388
+            }
+        ]
+        "#
+        .to_owned()
+    }
+
+    pub fn order_book_json() -> String {
+        r#"
+        {
+            "instrument": "EUR_USD",
+            "time": "2024-01-02T10:00:00Z",
+            "unixTime": "1704189600",
+            "price": "1.08400",
+            "bucketWidth": "0.00050",
+            "buckets": [
+                {
+                    "price": "1.08350",
+                    "longCountPercent": "0.3215",
+                    "shortCountPercent": "0.1904"
+                },
+                {
+                    "price": "1.08400",
+                    "longCountPercent": "0.2812",
+                    "shortCountPercent": "0.4055"
+                }
+            ]
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn position_book_json() -> String {
+        r#"
+        {
+            "instrument": "EUR_USD",
+            "time": "2024-01-02T10:00:00Z",
+            "unixTime": "1704189600",
+            "price": "1.08400",
+            "bucketWidth": "0.00050",
+            "buckets": [
+                {
+                    "price": "1.08350",
+                    "longCountPercent": "0.5120",
+                    "shortCountPercent": "0.2210"
+                }
+            ]
+        }
+        "#
+        .to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        number::number,
+        oanda::{
+            instrument::fixtures::{candles, instruments, instruments_json},
+            Candlestick, DayOfWeek, GuaranteedStopLossOrderModeForInstrument, Instrument,
+            InstrumentError, InstrumentType, TagName, TagType,
+        },
+    };
+
+    fn instrument(name: &str) -> Instrument {
+        instruments()
+            .into_iter()
+            .find(|instrument| instrument.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_instrument_pips() {
+        let eur_usd = instrument("EUR_USD");
+        assert_eq!(number("0.0001"), eur_usd.pip_size());
+        assert_eq!(number("25"), eur_usd.price_to_pips(number("0.0025")));
+        assert_eq!(number("0.0025"), eur_usd.pips_to_price(number("25")));
+
+        let usd_jpy = instrument("USD_JPY");
+        assert_eq!(number("0.01"), usd_jpy.pip_size());
+        assert_eq!(number("150"), usd_jpy.price_to_pips(number("1.5")));
+    }
+
+    #[test]
+    fn test_instrument_round_price() {
+        let eur_usd = instrument("EUR_USD");
+        assert_eq!(number("1.08403"), eur_usd.round_price(number("1.084028")));
+
+        let usd_jpy = instrument("USD_JPY");
+        assert_eq!(number("151.235"), usd_jpy.round_price(number("151.2349")));
+    }
+
+    #[test]
+    fn test_instrument_round_units() {
+        let eur_usd = instrument("EUR_USD");
+        assert_eq!(number("1000"), eur_usd.round_units(number("1000.7")));
+        assert_eq!(number("-1000"), eur_usd.round_units(number("-1000.7")));
+    }
+
+    #[test]
+    fn test_instrument_validate_price() {
+        let eur_usd = instrument("EUR_USD");
+        assert!(eur_usd.validate_price(number("1.08403")).is_ok());
+        assert_eq!(
+            Err(InstrumentError::PricePrecision {
+                price: number("1.084031"),
+                precision: 5
+            }),
+            eur_usd.validate_price(number("1.084031"))
+        );
+    }
+
+    #[test]
+    fn test_instrument_validate_units() {
+        let eur_usd = instrument("EUR_USD");
+        assert!(eur_usd.validate_units(number("1000")).is_ok());
+        assert!(eur_usd.validate_units(number("-1000")).is_ok());
+        assert!(matches!(
+            eur_usd.validate_units(number("10.5")),
+            Err(InstrumentError::UnitsPrecision { .. })
+        ));
+        assert!(matches!(
+            eur_usd.validate_units(number("0")),
+            Err(InstrumentError::BelowMinimumTradeSize { .. })
+        ));
+        assert!(matches!(
+            eur_usd.validate_units(number("-100000001")),
+            Err(InstrumentError::AboveMaximumOrderUnits { .. })
+        ));
+    }
+
+    #[test]
+    fn test_instrument_validate_trailing_stop_distance() {
+        let eur_usd = instrument("EUR_USD");
+        assert!(eur_usd
+            .validate_trailing_stop_distance(number("0.005"))
+            .is_ok());
+        assert!(matches!(
+            eur_usd.validate_trailing_stop_distance(number("0.0001")),
+            Err(InstrumentError::BelowMinimumTrailingStopDistance { .. })
+        ));
+        assert!(matches!(
+            eur_usd.validate_trailing_stop_distance(number("1.5")),
+            Err(InstrumentError::AboveMaximumTrailingStopDistance { .. })
+        ));
+        assert!(matches!(
+            eur_usd.validate_trailing_stop_distance(number("0.000501")),
+            Err(InstrumentError::PricePrecision { .. })
+        ));
+    }
+
+    #[test]
+    fn test_instruments_typed_enums() {
+        let instrument = &instruments()[0];
+
+        assert_eq!(InstrumentType::Currency, instrument.instrument_type);
+        assert_eq!(
+            GuaranteedStopLossOrderModeForInstrument::Disabled,
+            instrument.guaranteed_stop_loss_order_mode
+        );
+        assert!(instrument
+            .tags
+            .iter()
+            .any(|tag| tag.tag_type == TagType::AssetClass && tag.name == TagName::Currency));
+    }
+
+    #[test]
+    fn test_instruments_unknown_enum_values() {
+        let mut json: serde_json::Value = serde_json::from_str(&instruments_json()).unwrap();
+        json[0]["type"] = "CRYPTO".into();
+        json[0]["guaranteedStopLossOrderMode"] = "SOMETIMES".into();
+        json[0]["tags"][0]["type"] = "NEW_ASSET_CLASS".into();
+        json[0]["tags"][0]["name"] = "DIGITAL".into();
+        json[0]["financing"]["financingDaysOfWeek"][0]["dayOfWeek"] = "HOLIDAY".into();
+
+        let instruments: Vec<Instrument> = serde_json::from_value(json.clone()).unwrap();
+        let instrument = &instruments[0];
+
+        assert_eq!(
+            InstrumentType::Unknown("CRYPTO".to_string()),
+            instrument.instrument_type
+        );
+        assert_eq!(
+            GuaranteedStopLossOrderModeForInstrument::Unknown("SOMETIMES".to_string()),
+            instrument.guaranteed_stop_loss_order_mode
+        );
+        assert_eq!(
+            TagType::Unknown("NEW_ASSET_CLASS".to_string()),
+            instrument.tags[0].tag_type
+        );
+        assert_eq!(
+            DayOfWeek::Unknown("HOLIDAY".to_string()),
+            instrument.financing.financing_days_of_week[0].day_of_week
+        );
+
+        let serialized = serde_json::to_value(&instruments).unwrap();
+        assert_eq!(json[0]["type"], serialized[0]["type"]);
+        assert_eq!(json[0]["tags"], serialized[0]["tags"]);
+    }
+
+    #[test]
+    fn test_instruments_round_trip() {
+        let instruments = instruments();
+        let json = serde_json::to_string(&instruments).unwrap();
+
+        assert_eq!(
+            instruments,
+            serde_json::from_str::<Vec<Instrument>>(&json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_candles_round_trip() {
+        let candles = candles();
+        let json = serde_json::to_string(&candles).unwrap();
+
+        assert_eq!(
+            candles,
+            serde_json::from_str::<Vec<Candlestick>>(&json).unwrap()
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_instruments_serialize_matches_wire_format() {
+        use crate::oanda::instrument::fixtures::instruments_json;
+
+        let expected: serde_json::Value = serde_json::from_str(&instruments_json()).unwrap();
+        let actual = serde_json::to_value(instruments()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    #[test]
+    fn test_instruments_serialize_matches_wire_format_values() {
+        use serde_json::Value;
+
+        use crate::oanda::instrument::fixtures::instruments_json;
+
+        // f64 drops trailing zeros, e.g. "0.0001000" is sent back as "0.0001",
+        // so numeric strings are compared by value.
+        fn normalize(value: &mut Value) {
+            match value {
+                Value::String(s) => {
+                    if let Ok(number) = s.parse::<f64>() {
+                        *s = number.to_string();
+                    }
+                }
+                Value::Array(values) => values.iter_mut().for_each(normalize),
+                Value::Object(values) => values.values_mut().for_each(normalize),
+                _ => {}
+            }
+        }
+
+        let mut expected: serde_json::Value = serde_json::from_str(&instruments_json()).unwrap();
+        let mut actual = serde_json::to_value(instruments()).unwrap();
+        normalize(&mut expected);
+        normalize(&mut actual);
+
+        assert_eq!(expected, actual);
+    }
 }
@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+use crate::{
+    oanda::{Account, AccountChanges, AccountChangesResponse, AccountChangesState, Api, Order},
+    Error, Number,
+};
+
+/// Keeps a local copy of an account in sync by polling `/changes` from the
+/// last seen transaction id. Subscribers are notified after every poll.
+pub struct AccountMirror<A> {
+    api: A,
+    sender: watch::Sender<Account>,
+}
+
+impl<A> AccountMirror<A>
+where
+    A: Api + Send + Sync,
+{
+    pub async fn new(api: A) -> Result<Self, Error> {
+        let account = api.account().await?;
+        let (sender, _) = watch::channel(account);
+
+        Ok(AccountMirror { api, sender })
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<Account> {
+        self.sender.subscribe()
+    }
+
+    pub fn account(&self) -> watch::Ref<'_, Account> {
+        self.sender.borrow()
+    }
+
+    pub async fn sync(&self) -> Result<(), Error> {
+        let since = self.sender.borrow().summary.last_transaction_id.clone();
+        let response = self.api.account_changes(&since).await?;
+
+        self.sender
+            .send_modify(|account| apply_changes(account, response));
+
+        Ok(())
+    }
+
+    /// Polls until a request fails, returning that error so the caller can
+    /// decide whether to back off or rebuild the mirror from a fresh snapshot.
+    pub async fn run(&self, poll_interval: Duration) -> Error {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(err) = self.sync().await {
+                return err;
+            }
+        }
+    }
+}
+
+fn apply_changes(account: &mut Account, response: AccountChangesResponse) {
+    let AccountChangesResponse {
+        changes,
+        state,
+        last_transaction_id,
+    } = response;
+
+    apply_account_changes(account, changes);
+    apply_account_state(account, state);
+
+    account.summary.open_trade_count = account.trades.len() as i64;
+    account.summary.pending_order_count = account.orders.len() as i64;
+    account.summary.open_position_count = account
+        .positions
+        .iter()
+        .filter(|position| {
+            position.long.units != Number::default() || position.short.units != Number::default()
+        })
+        .count() as i64;
+    account.summary.last_transaction_id = last_transaction_id;
+}
+
+fn apply_account_changes(account: &mut Account, changes: AccountChanges) {
+    let finished_orders = changes
+        .orders_cancelled
+        .iter()
+        .chain(&changes.orders_filled)
+        .chain(&changes.orders_triggered)
+        .map(Order::id)
+        .collect::<Vec<_>>();
+    account
+        .orders
+        .retain(|order| !finished_orders.contains(&order.id()));
+    account.orders.extend(
+        changes
+            .orders_created
+            .into_iter()
+            .filter(|order| !finished_orders.contains(&order.id())),
+    );
+
+    for trade in changes
+        .trades_opened
+        .into_iter()
+        .chain(changes.trades_reduced)
+    {
+        match account.trades.iter_mut().find(|t| t.id == trade.id) {
+            Some(existing) => *existing = trade,
+            None => account.trades.push(trade),
+        }
+    }
+    account
+        .trades
+        .retain(|trade| !changes.trades_closed.iter().any(|t| t.id == trade.id));
+
+    for position in changes.positions {
+        match account
+            .positions
+            .iter_mut()
+            .find(|p| p.instrument == position.instrument)
+        {
+            Some(existing) => *existing = position,
+            None => account.positions.push(position),
+        }
+    }
+}
+
+fn apply_account_state(account: &mut Account, state: AccountChangesState) {
+    let summary = &mut account.summary;
+
+    summary.unrealized_pl = state.unrealized_pl;
+    summary.nav = state.nav;
+    summary.margin_used = state.margin_used;
+    summary.margin_available = state.margin_available;
+    summary.position_value = state.position_value;
+    summary.margin_closeout_unrealized_pl = state.margin_closeout_unrealized_pl;
+    summary.margin_closeout_nav = state.margin_closeout_nav;
+    summary.margin_closeout_margin_used = state.margin_closeout_margin_used;
+    summary.margin_closeout_percent = state.margin_closeout_percent;
+    summary.margin_closeout_position_value = state.margin_closeout_position_value;
+    summary.withdrawal_limit = state.withdrawal_limit;
+    summary.margin_call_margin_used = state.margin_call_margin_used;
+    summary.margin_call_percent = state.margin_call_percent;
+    summary.margin_call_enter_time = state.margin_call_enter_time;
+    summary.margin_call_extension_count = state.margin_call_extension_count;
+    summary.last_margin_call_extension_time = state.last_margin_call_extension_time;
+
+    if let Some(balance) = state.balance {
+        summary.balance = balance;
+    }
+    if let Some(pl) = state.pl {
+        summary.pl = pl;
+    }
+    if let Some(resettable_pl) = state.resettable_pl {
+        summary.resettable_pl = resettable_pl;
+    }
+    if let Some(financing) = state.financing {
+        summary.financing = financing;
+    }
+    if let Some(commission) = state.commission {
+        summary.commission = commission;
+    }
+    if state.dividend_adjustment.is_some() {
+        summary.dividend_adjustment = state.dividend_adjustment;
+    }
+    if state.guaranteed_execution_fees.is_some() {
+        summary.guaranteed_execution_fees = state.guaranteed_execution_fees;
+    }
+
+    for order_state in state.orders {
+        if let Some(Order::TrailingStopLoss(order)) = account
+            .orders
+            .iter_mut()
+            .find(|order| order.id() == order_state.id)
+        {
+            order.trailing_stop_value = order_state.trailing_stop_value;
+        }
+    }
+
+    for trade_state in state.trades {
+        if let Some(trade) = account.trades.iter_mut().find(|t| t.id == trade_state.id) {
+            trade.unrealized_pl = Some(trade_state.unrealized_pl);
+            trade.margin_used = Some(trade_state.margin_used);
+        }
+    }
+
+    for position_state in state.positions {
+        if let Some(position) = account
+            .positions
+            .iter_mut()
+            .find(|p| p.instrument == position_state.instrument)
+        {
+            position.unrealized_pl = position_state.net_unrealized_pl;
+            position.long.unrealized_pl = position_state.long_unrealized_pl;
+            position.short.unrealized_pl = position_state.short_unrealized_pl;
+            position.margin_used = Some(position_state.margin_used);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::{
+        number::number,
+        oanda::{
+            account::fixtures::{account_changes_response_json, account_summary_json},
+            order::fixtures::orders_json,
+            position::fixtures::positions_json,
+            AccountMirror, Client, Url,
+        },
+    };
+
+    static ACCOUNT_ID: &str = "account_id";
+    static AUTH_TOKEN: &str = "auth_token";
+
+    #[tokio::test]
+    async fn test_account_mirror_sync() {
+        let mut server = mockito::Server::new_async().await;
+        let url = server.url();
+
+        let mut account: serde_json::Value = serde_json::from_str(&account_summary_json()).unwrap();
+        account["orders"] = serde_json::from_str(&orders_json()).unwrap();
+        account["positions"] = serde_json::from_str(&positions_json()).unwrap();
+
+        let account_mock = server
+            .mock("GET", format!("/v3/accounts/{}", ACCOUNT_ID).as_str())
+            .with_status(200)
+            .with_body(
+                serde_json::json!({ "account": account, "lastTransactionID": "6383" }).to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut changes: serde_json::Value =
+            serde_json::from_str(&account_changes_response_json()).unwrap();
+        changes["changes"]["ordersCancelled"] = serde_json::json!([serde_json::from_str::<
+            serde_json::Value,
+        >(&orders_json())
+        .unwrap()[0]]);
+
+        let changes_mock = server
+            .mock(
+                "GET",
+                format!("/v3/accounts/{}/changes", ACCOUNT_ID).as_str(),
+            )
+            .match_query(Matcher::UrlEncoded(
+                "sinceTransactionID".into(),
+                "6383".into(),
+            ))
+            .with_status(200)
+            .with_body(changes.to_string())
+            .create_async()
+            .await;
+
+        let client = Client::new(
+            reqwest::Client::new(),
+            ACCOUNT_ID,
+            AUTH_TOKEN,
+            Url::Custom(url),
+        );
+
+        let mirror = AccountMirror::new(client).await.unwrap();
+        let mut receiver = mirror.subscribe();
+
+        mirror.sync().await.unwrap();
+
+        assert!(receiver.has_changed().unwrap());
+
+        let account = receiver.borrow_and_update();
+        assert_eq!("6385", account.summary.last_transaction_id);
+        assert_eq!(number("99993.1424"), account.summary.nav);
+        assert_eq!(number("4.3383"), account.summary.margin_used);
+
+        assert_eq!(1, account.orders.len());
+        assert_eq!("6371", account.orders[0].id());
+        assert_eq!(1, account.summary.pending_order_count);
+
+        assert_eq!(1, account.trades.len());
+        assert_eq!(Some(number("-0.005")), account.trades[0].unrealized_pl);
+        assert_eq!(1, account.summary.open_trade_count);
+
+        assert_eq!(Some(number("4.3383")), account.positions[0].margin_used);
+        assert_eq!(number("-0.005"), account.positions[0].unrealized_pl);
+
+        account_mock.assert();
+        changes_mock.assert();
+    }
+}
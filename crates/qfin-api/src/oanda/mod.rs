@@ -1,30 +1,195 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+pub mod account;
+pub use account::*;
 
 pub mod client;
 pub use client::Client;
 
+pub mod config;
+pub use config::*;
+
+pub mod currency;
+pub use currency::*;
+
 pub mod instrument;
 pub use instrument::*;
 
+pub mod mirror;
+pub use mirror::AccountMirror;
+
+pub mod order;
+pub use order::*;
+
+pub mod position;
+pub use position::*;
+
+pub mod pricing;
+pub use pricing::*;
+
+pub mod trade;
+pub use trade::*;
+
+pub mod transaction;
+pub use transaction::*;
+
 use crate::Error;
 
 #[async_trait]
 pub trait Api {
     async fn instruments(&self) -> Result<Vec<Instrument>, Error>;
+
+    async fn instrument_candles(
+        &self,
+        instrument: &str,
+        params: &CandlesParams,
+    ) -> Result<Vec<Candlestick>, Error>;
+
+    async fn order_book(
+        &self,
+        instrument: &str,
+        time: Option<DateTime<Utc>>,
+    ) -> Result<OrderBook, Error>;
+
+    async fn position_book(
+        &self,
+        instrument: &str,
+        time: Option<DateTime<Utc>>,
+    ) -> Result<PositionBook, Error>;
+
+    async fn pricing(&self, params: &PricingParams) -> Result<Pricing, Error>;
+
+    async fn create_order(&self, request: &OrderRequest) -> Result<CreateOrderResponse, Error>;
+
+    async fn orders(&self, params: &OrdersParams) -> Result<Vec<Order>, Error>;
+
+    async fn pending_orders(&self) -> Result<Vec<Order>, Error>;
+
+    async fn order(&self, specifier: &OrderSpecifier) -> Result<Order, Error>;
+
+    async fn replace_order(
+        &self,
+        specifier: &OrderSpecifier,
+        request: &OrderRequest,
+    ) -> Result<ReplaceOrderResponse, Error>;
+
+    async fn cancel_order(&self, specifier: &OrderSpecifier) -> Result<CancelOrderResponse, Error>;
+
+    async fn set_order_client_extensions(
+        &self,
+        specifier: &OrderSpecifier,
+        client_extensions: Option<&ClientExtensions>,
+        trade_client_extensions: Option<&ClientExtensions>,
+    ) -> Result<SetOrderClientExtensionsResponse, Error>;
+
+    async fn trades(&self, params: &TradesParams) -> Result<Vec<Trade>, Error>;
+
+    async fn open_trades(&self) -> Result<Vec<Trade>, Error>;
+
+    async fn trade(&self, specifier: &TradeSpecifier) -> Result<Trade, Error>;
+
+    async fn close_trade(
+        &self,
+        specifier: &TradeSpecifier,
+        units: CloseTradeUnits,
+    ) -> Result<CloseTradeResponse, Error>;
+
+    async fn set_trade_dependent_orders(
+        &self,
+        specifier: &TradeSpecifier,
+        orders: &TradeDependentOrders,
+    ) -> Result<SetTradeDependentOrdersResponse, Error>;
+
+    async fn positions(&self) -> Result<Vec<Position>, Error>;
+
+    async fn open_positions(&self) -> Result<Vec<Position>, Error>;
+
+    async fn position(&self, instrument: &str) -> Result<Position, Error>;
+
+    async fn close_position(
+        &self,
+        instrument: &str,
+        request: &ClosePositionRequest,
+    ) -> Result<ClosePositionResponse, Error>;
+
+    async fn accounts(&self) -> Result<Vec<AccountProperties>, Error>;
+
+    async fn account(&self) -> Result<Account, Error>;
+
+    async fn account_summary(&self) -> Result<AccountSummary, Error>;
+
+    async fn account_instruments(
+        &self,
+        params: &AccountInstrumentsParams,
+    ) -> Result<Vec<Instrument>, Error>;
+
+    async fn account_changes(
+        &self,
+        since_transaction_id: &str,
+    ) -> Result<AccountChangesResponse, Error>;
+
+    async fn transactions(&self, params: &TransactionsParams) -> Result<TransactionPages, Error>;
+
+    async fn transaction(&self, id: &str) -> Result<Transaction, Error>;
+
+    async fn transactions_id_range(
+        &self,
+        params: &TransactionsIdRangeParams,
+    ) -> Result<Vec<Transaction>, Error>;
+
+    async fn transactions_since_id(
+        &self,
+        params: &TransactionsSinceIdParams,
+    ) -> Result<Vec<Transaction>, Error>;
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Url {
     Custom(String),
+    /// Separate custom hosts for REST requests and streams.
+    Hosts {
+        api: String,
+        stream: String,
+    },
     Live,
     Practice,
 }
 
+impl Url {
+    pub fn stream_url(&self) -> String {
+        match self {
+            Url::Custom(s) => s.to_string(),
+            Url::Hosts { stream, .. } => stream.to_string(),
+            Url::Live => "https://stream-fxtrade.oanda.com".to_string(),
+            Url::Practice => "https://stream-fxpractice.oanda.com".to_string(),
+        }
+    }
+}
+
+impl FromStr for Url {
+    type Err = Error;
+
+    /// Parses `live`, `practice` or an `http(s)://` URL.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "live" => Ok(Url::Live),
+            "practice" => Ok(Url::Practice),
+            s if s.starts_with("https://") || s.starts_with("http://") => {
+                Ok(Url::Custom(s.trim_end_matches('/').to_string()))
+            }
+            s => Err(Error::Config(format!("invalid oanda environment: {s}"))),
+        }
+    }
+}
+
 impl fmt::Display for Url {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Url::Custom(s) => write!(f, "{}", s),
+            Url::Hosts { api, .. } => write!(f, "{}", api),
             Url::Live => write!(f, "https://api-fxtrade.oanda.com"),
             Url::Practice => write!(f, "https://api-fxpractice.oanda.com"),
         }
@@ -43,6 +208,15 @@ mod tests {
         assert_eq!(want, Url::Custom(custom_url).to_string())
     }
 
+    #[test]
+    fn test_url_to_string_hosts() {
+        let url = Url::Hosts {
+            api: "https://api.example.com".to_string(),
+            stream: "https://stream.example.com".to_string(),
+        };
+        assert_eq!("https://api.example.com", url.to_string())
+    }
+
     #[test]
     fn test_url_to_string_live() {
         let want = "https://api-fxtrade.oanda.com";
@@ -54,4 +228,43 @@ mod tests {
         let want = "https://api-fxpractice.oanda.com";
         assert_eq!(want, Url::Practice.to_string())
     }
+
+    #[test]
+    fn test_url_from_str() {
+        assert!(matches!("live".parse(), Ok(Url::Live)));
+        assert!(matches!("practice".parse(), Ok(Url::Practice)));
+        assert!(
+            matches!("http://localhost:8080/".parse(), Ok(Url::Custom(url)) if url == "http://localhost:8080")
+        );
+        assert!("fxtrade".parse::<Url>().is_err());
+    }
+
+    #[test]
+    fn test_url_stream_url_custom() {
+        let want = "https://example.com";
+        let custom_url = want.to_string();
+
+        assert_eq!(want, Url::Custom(custom_url).stream_url())
+    }
+
+    #[test]
+    fn test_url_stream_url_hosts() {
+        let url = Url::Hosts {
+            api: "https://api.example.com".to_string(),
+            stream: "https://stream.example.com".to_string(),
+        };
+        assert_eq!("https://stream.example.com", url.stream_url())
+    }
+
+    #[test]
+    fn test_url_stream_url_live() {
+        let want = "https://stream-fxtrade.oanda.com";
+        assert_eq!(want, Url::Live.stream_url())
+    }
+
+    #[test]
+    fn test_url_stream_url_practice() {
+        let want = "https://stream-fxpractice.oanda.com";
+        assert_eq!(want, Url::Practice.stream_url())
+    }
 }
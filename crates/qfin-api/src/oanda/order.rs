@@ -0,0 +1,888 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    deserializer::{de_option_string_as_number, de_string_as_number},
+    macros::specifier,
+    oanda::{
+        OrderCancelTransaction, OrderClientExtensionsModifyTransaction, OrderFillTransaction,
+        Transaction,
+    },
+    serializer::{ser_comma_separated, ser_number_as_string, ser_option_number_as_string},
+    Number,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Order {
+    Market(PlacedOrder<MarketOrderRequest>),
+    Limit(PlacedOrder<LimitOrderRequest>),
+    Stop(PlacedOrder<StopOrderRequest>),
+    MarketIfTouched(PlacedOrder<MarketIfTouchedOrderRequest>),
+    TakeProfit(PlacedOrder<TakeProfitOrderRequest>),
+    StopLoss(PlacedOrder<StopLossOrderRequest>),
+    GuaranteedStopLoss(PlacedOrder<GuaranteedStopLossOrderRequest>),
+    TrailingStopLoss(PlacedOrder<TrailingStopLossOrderRequest>),
+    /// An order type that isn't modelled yet, e.g. `FIXED_PRICE`.
+    #[serde(untagged)]
+    Unknown(UnknownOrder),
+}
+
+impl Order {
+    pub fn id(&self) -> &str {
+        match self {
+            Order::Market(order) => &order.id,
+            Order::Limit(order) => &order.id,
+            Order::Stop(order) => &order.id,
+            Order::MarketIfTouched(order) => &order.id,
+            Order::TakeProfit(order) => &order.id,
+            Order::StopLoss(order) => &order.id,
+            Order::GuaranteedStopLoss(order) => &order.id,
+            Order::TrailingStopLoss(order) => &order.id,
+            Order::Unknown(order) => &order.id,
+        }
+    }
+
+    pub fn state(&self) -> OrderState {
+        match self {
+            Order::Market(order) => order.state,
+            Order::Limit(order) => order.state,
+            Order::Stop(order) => order.state,
+            Order::MarketIfTouched(order) => order.state,
+            Order::TakeProfit(order) => order.state,
+            Order::StopLoss(order) => order.state,
+            Order::GuaranteedStopLoss(order) => order.state,
+            Order::TrailingStopLoss(order) => order.state,
+            Order::Unknown(order) => order.state,
+        }
+    }
+}
+
+/// Keeps the fields of an unmodelled order so it serializes back unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnknownOrder {
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub id: String,
+    pub state: OrderState,
+    #[serde(flatten)]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacedOrder<T> {
+    pub id: String,
+    pub create_time: DateTime<Utc>,
+    pub state: OrderState,
+    #[serde(flatten)]
+    pub request: T,
+    #[serde(
+        rename = "fillingTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filling_transaction_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "tradeOpenedID", skip_serializing_if = "Option::is_none")]
+    pub trade_opened_id: Option<String>,
+    #[serde(rename = "tradeReducedID", skip_serializing_if = "Option::is_none")]
+    pub trade_reduced_id: Option<String>,
+    #[serde(rename = "tradeClosedIDs", default)]
+    pub trade_closed_ids: Vec<String>,
+    #[serde(
+        rename = "cancellingTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cancelling_transaction_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "replacesOrderID", skip_serializing_if = "Option::is_none")]
+    pub replaces_order_id: Option<String>,
+    #[serde(rename = "replacedByOrderID", skip_serializing_if = "Option::is_none")]
+    pub replaced_by_order_id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub trailing_stop_value: Option<Number>,
+}
+
+pub type MarketOrder = PlacedOrder<MarketOrderRequest>;
+pub type LimitOrder = PlacedOrder<LimitOrderRequest>;
+pub type StopOrder = PlacedOrder<StopOrderRequest>;
+pub type MarketIfTouchedOrder = PlacedOrder<MarketIfTouchedOrderRequest>;
+pub type TakeProfitOrder = PlacedOrder<TakeProfitOrderRequest>;
+pub type StopLossOrder = PlacedOrder<StopLossOrderRequest>;
+pub type GuaranteedStopLossOrder = PlacedOrder<GuaranteedStopLossOrderRequest>;
+pub type TrailingStopLossOrder = PlacedOrder<TrailingStopLossOrderRequest>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderState {
+    Pending,
+    Filled,
+    Triggered,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStateFilter {
+    Pending,
+    Filled,
+    Triggered,
+    Cancelled,
+    All,
+}
+
+specifier! {
+    pub enum OrderSpecifier
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersParams {
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "ser_comma_separated"
+    )]
+    pub ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<OrderStateFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    #[serde(rename = "beforeID", skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderRequest {
+    Market(MarketOrderRequest),
+    Limit(LimitOrderRequest),
+    Stop(StopOrderRequest),
+    MarketIfTouched(MarketIfTouchedOrderRequest),
+    TakeProfit(TakeProfitOrderRequest),
+    StopLoss(StopLossOrderRequest),
+    GuaranteedStopLoss(GuaranteedStopLossOrderRequest),
+    TrailingStopLoss(TrailingStopLossOrderRequest),
+}
+
+impl OrderRequest {
+    pub fn client_extensions(&self) -> Option<&ClientExtensions> {
+        match self {
+            OrderRequest::Market(request) => request.client_extensions.as_ref(),
+            OrderRequest::Limit(request) => request.client_extensions.as_ref(),
+            OrderRequest::Stop(request) => request.client_extensions.as_ref(),
+            OrderRequest::MarketIfTouched(request) => request.client_extensions.as_ref(),
+            OrderRequest::TakeProfit(request) => request.client_extensions.as_ref(),
+            OrderRequest::StopLoss(request) => request.client_extensions.as_ref(),
+            OrderRequest::GuaranteedStopLoss(request) => request.client_extensions.as_ref(),
+            OrderRequest::TrailingStopLoss(request) => request.client_extensions.as_ref(),
+        }
+    }
+
+    pub fn client_id(&self) -> Option<&str> {
+        self.client_extensions()?.id.as_deref()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketOrderRequest {
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price_bound: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_fill: Option<OrderPositionFill>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrderRequest {
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_fill: Option<OrderPositionFill>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopOrderRequest {
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price_bound: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_fill: Option<OrderPositionFill>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketIfTouchedOrderRequest {
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price_bound: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_fill: Option<OrderPositionFill>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakeProfitOrderRequest {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<String>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopLossOrderRequest {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub distance: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuaranteedStopLossOrderRequest {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub distance: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailingStopLossOrderRequest {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<String>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub distance: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientExtensions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakeProfitDetails {
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopLossDetails {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub distance: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuaranteedStopLossDetails {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub distance: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailingStopLossDetails {
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub distance: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TimeInForce {
+    Gtc,
+    Gtd,
+    Gfd,
+    Fok,
+    Ioc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderPositionFill {
+    OpenOnly,
+    ReduceFirst,
+    ReduceOnly,
+    Default,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderTriggerCondition {
+    Default,
+    Inverse,
+    Bid,
+    Ask,
+    Mid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrderResponse {
+    pub order_create_transaction: Transaction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_reissue_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_reissue_reject_transaction: Option<Transaction>,
+    #[serde(rename = "relatedTransactionIDs", default)]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceOrderResponse {
+    pub order_cancel_transaction: OrderCancelTransaction,
+    pub order_create_transaction: Transaction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_reissue_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_reissue_reject_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacing_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "relatedTransactionIDs", default)]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderResponse {
+    pub order_cancel_transaction: OrderCancelTransaction,
+    #[serde(rename = "relatedTransactionIDs", default)]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetOrderClientExtensionsResponse {
+    pub order_client_extensions_modify_transaction: OrderClientExtensionsModifyTransaction,
+    #[serde(rename = "relatedTransactionIDs", default)]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    pub fn create_order_response_json() -> String {
+        r#"
+        {
+            "orderCreateTransaction": {
+                "type": "MARKET_ORDER",
+                "instrument": "EUR_USD",
+                "units": "100",
+                "timeInForce": "FOK",
+                "positionFill": "DEFAULT",
+                "reason": "CLIENT_ORDER",
+                "clientExtensions": {
+                    "id": "my-order",
+                    "tag": "strategy-a"
+                },
+                "id": "6368",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6368",
+                "requestID": "61234567890123456",
+                "time": "2024-01-02T10:00:03.123456789Z"
+            },
+            "orderFillTransaction": {
+                "type": "ORDER_FILL",
+                "orderID": "6368",
+                "clientOrderID": "my-order",
+                "instrument": "EUR_USD",
+                "units": "100",
+                "price": "1.08402",
+                "fullVWAP": "1.08402",
+                "reason": "MARKET_ORDER",
+                "pl": "0.0000",
+                "financing": "0.0000",
+                "commission": "0.0000",
+                "guaranteedExecutionFee": "0.0000",
+                "accountBalance": "99993.1234",
+                "tradeOpened": {
+                    "tradeID": "6369",
+                    "units": "100",
+                    "price": "1.08402",
+                    "guaranteedExecutionFee": "0.0000",
+                    "halfSpreadCost": "0.0060",
+                    "initialMarginRequired": "2.1680"
+                },
+                "halfSpreadCost": "0.0060",
+                "id": "6369",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6368",
+                "requestID": "61234567890123456",
+                "time": "2024-01-02T10:00:03.123456789Z"
+            },
+            "relatedTransactionIDs": [
+                "6368",
+                "6369"
+            ],
+            "lastTransactionID": "6369"
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn order_rejection_json() -> String {
+        r#"
+        {
+            "orderRejectTransaction": {
+                "type": "LIMIT_ORDER_REJECT",
+                "instrument": "EUR_USD",
+                "units": "100",
+                "price": "1.0",
+                "timeInForce": "GTC",
+                "positionFill": "DEFAULT",
+                "triggerCondition": "DEFAULT",
+                "reason": "CLIENT_ORDER",
+                "rejectReason": "PRICE_PRECISION_EXCEEDED",
+                "id": "6370",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6370",
+                "time": "2024-01-02T10:00:04.000000000Z"
+            },
+            "relatedTransactionIDs": [
+                "6370"
+            ],
+            "lastTransactionID": "6370",
+            "errorCode": "PRICE_PRECISION_EXCEEDED",
+            "errorMessage": "The price specifed contains more precision than is allowed"
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn orders_json() -> String {
+        r#"
+        [
+            {
+                "id": "6372",
+                "createTime": "2024-01-02T10:05:00.000000000Z",
+                "type": "LIMIT",
+                "instrument": "EUR_USD",
+                "units": "100",
+                "timeInForce": "GTC",
+                "price": "1.07500",
+                "triggerCondition": "DEFAULT",
+                "partialFill": "DEFAULT_FILL",
+                "positionFill": "DEFAULT",
+                "state": "PENDING",
+                "clientExtensions": {
+                    "id": "my-limit"
+                }
+            },
+            {
+                "id": "6371",
+                "createTime": "2024-01-02T10:00:03.123456789Z",
+                "type": "TAKE_PROFIT",
+                "tradeID": "6369",
+                "price": "1.10000",
+                "timeInForce": "GTC",
+                "triggerCondition": "DEFAULT",
+                "state": "PENDING"
+            }
+        ]
+        "#
+        .to_owned()
+    }
+
+    pub fn replace_order_response_json() -> String {
+        r#"
+        {
+            "orderCancelTransaction": {
+                "type": "ORDER_CANCEL",
+                "orderID": "6372",
+                "clientOrderID": "my-limit",
+                "replacedByOrderID": "6374",
+                "reason": "CLIENT_REQUEST_REPLACED",
+                "id": "6373",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6373",
+                "requestID": "61234567890123460",
+                "time": "2024-01-02T10:10:00.000000000Z"
+            },
+            "orderCreateTransaction": {
+                "type": "LIMIT_ORDER",
+                "instrument": "EUR_USD",
+                "units": "200",
+                "price": "1.07400",
+                "timeInForce": "GTC",
+                "triggerCondition": "DEFAULT",
+                "positionFill": "DEFAULT",
+                "reason": "REPLACEMENT",
+                "replacesOrderID": "6372",
+                "id": "6374",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6373",
+                "requestID": "61234567890123460",
+                "time": "2024-01-02T10:10:00.000000000Z"
+            },
+            "relatedTransactionIDs": [
+                "6373",
+                "6374"
+            ],
+            "lastTransactionID": "6374"
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn cancel_order_response_json() -> String {
+        r#"
+        {
+            "orderCancelTransaction": {
+                "type": "ORDER_CANCEL",
+                "orderID": "6374",
+                "reason": "CLIENT_REQUEST",
+                "id": "6375",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6375",
+                "requestID": "61234567890123461",
+                "time": "2024-01-02T10:15:00.000000000Z"
+            },
+            "relatedTransactionIDs": [
+                "6375"
+            ],
+            "lastTransactionID": "6375"
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn set_order_client_extensions_response_json() -> String {
+        r#"
+        {
+            "orderClientExtensionsModifyTransaction": {
+                "type": "ORDER_CLIENT_EXTENSIONS_MODIFY",
+                "orderID": "6372",
+                "clientOrderID": "my-limit",
+                "clientExtensionsModify": {
+                    "id": "my-limit",
+                    "comment": "moved to desk b"
+                },
+                "id": "6376",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6376",
+                "requestID": "61234567890123462",
+                "time": "2024-01-02T10:20:00.000000000Z"
+            },
+            "relatedTransactionIDs": [
+                "6376"
+            ],
+            "lastTransactionID": "6376"
+        }
+        "#
+        .to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oanda::{order::fixtures::orders_json, Order, OrderSpecifier};
+
+    #[test]
+    fn test_order_specifier_to_string() {
+        assert_eq!("6372", OrderSpecifier::Id("6372".to_string()).to_string());
+        assert_eq!(
+            "@my-order",
+            OrderSpecifier::ClientId("my-order".to_string()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_order_specifier_from_str() {
+        assert_eq!(
+            OrderSpecifier::Id("6372".to_string()),
+            "6372".parse().unwrap()
+        );
+        assert_eq!(
+            OrderSpecifier::ClientId("my-order".to_string()),
+            "@my-order".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_orders_round_trip() {
+        let orders: Vec<Order> = serde_json::from_str(&orders_json()).unwrap();
+        let json = serde_json::to_string(&orders).unwrap();
+
+        assert_eq!(orders, serde_json::from_str::<Vec<Order>>(&json).unwrap());
+    }
+}
@@ -0,0 +1,310 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{
+    deserializer::{de_option_string_as_number, de_string_as_number},
+    oanda::{ClientExtensions, OrderCancelTransaction, OrderFillTransaction, Transaction},
+    serializer::{ser_number_as_string, ser_option_number_as_string},
+    Number,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub pl: Number,
+    #[serde(
+        rename = "unrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub unrealized_pl: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub margin_used: Option<Number>,
+    #[serde(
+        rename = "resettablePL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub resettable_pl: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub financing: Option<Number>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub commission: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub dividend_adjustment: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub guaranteed_execution_fees: Option<Number>,
+    pub long: PositionSide,
+    pub short: PositionSide,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionSide {
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub average_price: Option<Number>,
+    #[serde(rename = "tradeIDs", default)]
+    pub trade_ids: Vec<String>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub pl: Number,
+    #[serde(
+        rename = "unrealizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub unrealized_pl: Number,
+    #[serde(
+        rename = "resettablePL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub resettable_pl: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub financing: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub dividend_adjustment: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub guaranteed_execution_fees: Option<Number>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClosePositionUnits {
+    All,
+    None,
+    Units(Number),
+}
+
+impl Serialize for ClosePositionUnits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ClosePositionUnits::All => serializer.serialize_str("ALL"),
+            ClosePositionUnits::None => serializer.serialize_str("NONE"),
+            ClosePositionUnits::Units(units) => serializer.serialize_str(&units.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosePositionRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_units: Option<ClosePositionUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_client_extensions: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_units: Option<ClosePositionUnits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosePositionResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_order_create_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_order_create_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "relatedTransactionIDs", default)]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    pub fn positions_json() -> String {
+        r#"
+        [
+            {
+                "instrument": "EUR_USD",
+                "long": {
+                    "units": "100",
+                    "averagePrice": "1.08402",
+                    "pl": "0.0240",
+                    "resettablePL": "0.0240",
+                    "financing": "-0.0012",
+                    "dividendAdjustment": "0.0000",
+                    "guaranteedExecutionFees": "0.0000",
+                    "tradeIDs": [
+                        "6369"
+                    ],
+                    "unrealizedPL": "0.0150"
+                },
+                "short": {
+                    "units": "0",
+                    "pl": "-1.2500",
+                    "resettablePL": "-1.2500",
+                    "financing": "0.0000",
+                    "dividendAdjustment": "0.0000",
+                    "guaranteedExecutionFees": "0.0000",
+                    "unrealizedPL": "0.0000"
+                },
+                "pl": "-1.2260",
+                "resettablePL": "-1.2260",
+                "financing": "-0.0012",
+                "commission": "0.0000",
+                "dividendAdjustment": "0.0000",
+                "guaranteedExecutionFees": "0.0000",
+                "unrealizedPL": "0.0150",
+                "marginUsed": "2.1683"
+            }
+        ]
+        "#
+        .to_owned()
+    }
+
+    pub fn close_position_response_json() -> String {
+        r#"
+        {
+            "longOrderCreateTransaction": {
+                "type": "MARKET_ORDER",
+                "instrument": "EUR_USD",
+                "units": "-100",
+                "timeInForce": "FOK",
+                "positionFill": "REDUCE_ONLY",
+                "reason": "POSITION_CLOSEOUT",
+                "longPositionCloseout": {
+                    "instrument": "EUR_USD",
+                    "units": "ALL"
+                },
+                "id": "6382",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6382",
+                "requestID": "61234567890123480",
+                "time": "2024-01-02T12:00:00.000000000Z"
+            },
+            "longOrderFillTransaction": {
+                "type": "ORDER_FILL",
+                "orderID": "6382",
+                "instrument": "EUR_USD",
+                "units": "-100",
+                "price": "1.08420",
+                "fullVWAP": "1.08420",
+                "reason": "MARKET_ORDER_POSITION_CLOSEOUT",
+                "pl": "0.0180",
+                "financing": "0.0000",
+                "commission": "0.0000",
+                "accountBalance": "99993.1654",
+                "tradesClosed": [
+                    {
+                        "tradeID": "6369",
+                        "units": "-100",
+                        "price": "1.08420",
+                        "realizedPL": "0.0180",
+                        "financing": "0.0000"
+                    }
+                ],
+                "id": "6383",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6382",
+                "requestID": "61234567890123480",
+                "time": "2024-01-02T12:00:00.000000000Z"
+            },
+            "relatedTransactionIDs": [
+                "6382",
+                "6383"
+            ],
+            "lastTransactionID": "6383"
+        }
+        "#
+        .to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        number::number,
+        oanda::{ClosePositionRequest, ClosePositionUnits},
+    };
+
+    #[test]
+    fn test_close_position_request_serialize() {
+        let request = ClosePositionRequest {
+            long_units: Some(ClosePositionUnits::All),
+            short_units: Some(ClosePositionUnits::None),
+            ..Default::default()
+        };
+        assert_eq!(
+            r#"{"longUnits":"ALL","shortUnits":"NONE"}"#,
+            serde_json::to_string(&request).unwrap()
+        );
+
+        let request = ClosePositionRequest {
+            short_units: Some(ClosePositionUnits::Units(number("250"))),
+            ..Default::default()
+        };
+        assert_eq!(
+            r#"{"shortUnits":"250"}"#,
+            serde_json::to_string(&request).unwrap()
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    deserializer::de_string_as_number,
+    serializer::{ser_comma_separated, ser_number_as_number, ser_number_as_string},
+    Error, Number,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientPrice {
+    pub instrument: String,
+    pub time: DateTime<Utc>,
+    pub tradeable: bool,
+    pub bids: Vec<PriceBucket>,
+    pub asks: Vec<PriceBucket>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub closeout_bid: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub closeout_ask: Number,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceBucket {
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(serialize_with = "ser_number_as_number")]
+    pub liquidity: Number,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HomeConversions {
+    pub currency: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub account_gain: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub account_loss: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub position_value: Number,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pricing {
+    pub prices: Vec<ClientPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_conversions: Option<Vec<HomeConversions>>,
+    pub time: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PricingParams {
+    #[serde(serialize_with = "ser_comma_separated")]
+    pub instruments: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_home_conversions: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PricingHeartbeat {
+    pub time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PricingStreamMessage {
+    #[serde(rename = "PRICE")]
+    Price(ClientPrice),
+    #[serde(rename = "HEARTBEAT")]
+    Heartbeat(PricingHeartbeat),
+}
+
+#[derive(Debug)]
+pub enum PricingStreamEvent {
+    Connected,
+    Message(PricingStreamMessage),
+    Disconnected {
+        error: Error,
+        reconnect_in: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PricingStreamParams {
+    #[serde(serialize_with = "ser_comma_separated")]
+    pub instruments: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_home_conversions: Option<bool>,
+    #[serde(skip)]
+    pub heartbeat_timeout: Duration,
+}
+
+impl Default for PricingStreamParams {
+    fn default() -> Self {
+        // OANDA sends a heartbeat every 5 seconds.
+        PricingStreamParams {
+            instruments: Vec::new(),
+            snapshot: None,
+            include_home_conversions: None,
+            heartbeat_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    pub fn client_price_json() -> String {
+        r#"
+        {
+            "type": "PRICE",
+            "instrument": "EUR_USD",
+            "time": "2024-01-02T10:00:01.512345678Z",
+            "tradeable": true,
+            "bids": [
+                {
+                    "price": "1.08390",
+                    "liquidity": 1000000
+                },
+                {
+                    "price": "1.08388",
+                    "liquidity": 2000000
+                }
+            ],
+            "asks": [
+                {
+                    "price": "1.08402",
+                    "liquidity": 1000000
+                },
+                {
+                    "price": "1.08404",
+                    "liquidity": 2000000
+                }
+            ],
+            "closeoutBid": "1.08388",
+            "closeoutAsk": "1.08404"
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn pricing_json() -> String {
+        format!(
+            r#"
+            {{
+                "prices": [{}],
+                "homeConversions": [
+                    {{
+                        "currency": "USD",
+                        "accountGain": "1",
+                        "accountLoss": "1",
+                        "positionValue": "1"
+                    }},
+                    {{
+                        "currency": "EUR",
+                        "accountGain": "1.08402",
+                        "accountLoss": "1.08390",
+                        "positionValue": "1.08396"
+                    }}
+                ],
+                "time": "2024-01-02T10:00:02.000000000Z"
+            }}
+            "#,
+            client_price_json()
+        )
+    }
+
+    pub fn pricing_heartbeat_json() -> String {
+        r#"{"type": "HEARTBEAT", "time": "2024-01-02T10:00:05.000000000Z"}"#.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::{fixtures::client_price_json, ClientPrice};
+
+    fn serialize(json: &str) -> Value {
+        let price: ClientPrice = serde_json::from_str(json).unwrap();
+        let mut value = serde_json::to_value(&price).unwrap();
+        value
+            .as_object_mut()
+            .unwrap()
+            .insert("type".to_owned(), "PRICE".into());
+        value
+    }
+
+    #[test]
+    fn test_client_price_liquidity_is_json_number() {
+        let value = serialize(&client_price_json());
+
+        assert_eq!(1_000_000, value["bids"][0]["liquidity"]);
+        assert_eq!(2_000_000, value["asks"][1]["liquidity"]);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_client_price_round_trip() {
+        let expected: Value = serde_json::from_str(&client_price_json()).unwrap();
+
+        assert_eq!(expected, serialize(&client_price_json()));
+    }
+}
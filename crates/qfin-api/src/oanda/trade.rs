@@ -0,0 +1,463 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{
+    deserializer::{de_option_string_as_number, de_string_as_number},
+    macros::specifier,
+    oanda::{
+        ClientExtensions, GuaranteedStopLossDetails, GuaranteedStopLossOrder,
+        OrderCancelTransaction, OrderFillTransaction, StopLossDetails, StopLossOrder,
+        TakeProfitDetails, TakeProfitOrder, TrailingStopLossDetails, TrailingStopLossOrder,
+        Transaction,
+    },
+    serializer::{ser_comma_separated, ser_number_as_string, ser_option_number_as_string},
+    Number,
+};
+
+/// A trade and its dependent orders. [`TradeSummary`] only carries the ids of
+/// the dependent orders.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade<O = TradeOrders> {
+    pub id: String,
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    pub open_time: DateTime<Utc>,
+    pub state: TradeState,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub initial_units: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub initial_margin_required: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub current_units: Number,
+    #[serde(
+        rename = "realizedPL",
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub realized_pl: Number,
+    #[serde(
+        rename = "unrealizedPL",
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub unrealized_pl: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub margin_used: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub average_close_price: Option<Number>,
+    #[serde(rename = "closingTransactionIDs", default)]
+    pub closing_transaction_ids: Vec<String>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub financing: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub dividend_adjustment: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    #[serde(flatten)]
+    pub orders: O,
+}
+
+pub type TradeSummary = Trade<TradeOrderIds>;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeOrders {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_order: Option<TakeProfitOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order: Option<StopLossOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order: Option<GuaranteedStopLossOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_order: Option<TrailingStopLossOrder>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeOrderIds {
+    #[serde(rename = "takeProfitOrderID", skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_id: Option<String>,
+    #[serde(rename = "stopLossOrderID", skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_id: Option<String>,
+    #[serde(
+        rename = "guaranteedStopLossOrderID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub guaranteed_stop_loss_order_id: Option<String>,
+    #[serde(
+        rename = "trailingStopLossOrderID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub trailing_stop_loss_order_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TradeState {
+    Open,
+    Closed,
+    CloseWhenTradeable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TradeStateFilter {
+    Open,
+    Closed,
+    CloseWhenTradeable,
+    All,
+}
+
+specifier! {
+    pub enum TradeSpecifier
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradesParams {
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "ser_comma_separated"
+    )]
+    pub ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<TradeStateFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    #[serde(rename = "beforeID", skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CloseTradeUnits {
+    All,
+    Units(Number),
+}
+
+impl Serialize for CloseTradeUnits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            CloseTradeUnits::All => serializer.serialize_str("ALL"),
+            CloseTradeUnits::Units(units) => serializer.serialize_str(&units.to_string()),
+        }
+    }
+}
+
+/// Dependent orders to set on a trade. `None` leaves an order untouched,
+/// `Some(None)` cancels it and `Some(Some(_))` creates or replaces it.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeDependentOrders {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<Option<TakeProfitDetails>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<Option<StopLossDetails>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss: Option<Option<TrailingStopLossDetails>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss: Option<Option<GuaranteedStopLossDetails>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseTradeResponse {
+    pub order_create_transaction: Transaction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "relatedTransactionIDs", default)]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTradeDependentOrdersResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_created_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_created_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_order_transaction: Option<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_transaction: Option<Transaction>,
+    #[serde(rename = "relatedTransactionIDs", default)]
+    pub related_transaction_ids: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    pub fn trades_json() -> String {
+        r#"
+        [
+            {
+                "id": "6369",
+                "instrument": "EUR_USD",
+                "price": "1.08402",
+                "openTime": "2024-01-02T10:00:03.123456789Z",
+                "initialUnits": "100",
+                "initialMarginRequired": "2.1680",
+                "state": "OPEN",
+                "currentUnits": "100",
+                "realizedPL": "0.0000",
+                "financing": "-0.0012",
+                "dividendAdjustment": "0.0000",
+                "unrealizedPL": "0.0150",
+                "marginUsed": "2.1683",
+                "clientExtensions": {
+                    "id": "my-trade"
+                },
+                "takeProfitOrder": {
+                    "id": "6371",
+                    "createTime": "2024-01-02T10:00:03.123456789Z",
+                    "type": "TAKE_PROFIT",
+                    "tradeID": "6369",
+                    "price": "1.10000",
+                    "timeInForce": "GTC",
+                    "triggerCondition": "DEFAULT",
+                    "state": "PENDING"
+                },
+                "trailingStopLossOrder": {
+                    "id": "6377",
+                    "createTime": "2024-01-02T10:25:00.000000000Z",
+                    "type": "TRAILING_STOP_LOSS",
+                    "tradeID": "6369",
+                    "distance": "0.00500",
+                    "timeInForce": "GTC",
+                    "triggerCondition": "DEFAULT",
+                    "trailingStopValue": "1.07902",
+                    "state": "PENDING"
+                }
+            }
+        ]
+        "#
+        .to_owned()
+    }
+
+    pub fn close_trade_response_json() -> String {
+        r#"
+        {
+            "orderCreateTransaction": {
+                "type": "MARKET_ORDER",
+                "instrument": "EUR_USD",
+                "units": "-50",
+                "timeInForce": "FOK",
+                "positionFill": "REDUCE_ONLY",
+                "reason": "TRADE_CLOSE",
+                "tradeClose": {
+                    "tradeID": "6369",
+                    "clientTradeID": "my-trade",
+                    "units": "50"
+                },
+                "id": "6378",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6378",
+                "requestID": "61234567890123470",
+                "time": "2024-01-02T11:00:00.000000000Z"
+            },
+            "orderFillTransaction": {
+                "type": "ORDER_FILL",
+                "orderID": "6378",
+                "instrument": "EUR_USD",
+                "units": "-50",
+                "price": "1.08450",
+                "fullVWAP": "1.08450",
+                "reason": "MARKET_ORDER_TRADE_CLOSE",
+                "pl": "0.0240",
+                "financing": "0.0000",
+                "commission": "0.0000",
+                "accountBalance": "99993.1474",
+                "tradeReduced": {
+                    "tradeID": "6369",
+                    "units": "-50",
+                    "price": "1.08450",
+                    "realizedPL": "0.0240",
+                    "financing": "0.0000",
+                    "halfSpreadCost": "0.0030"
+                },
+                "halfSpreadCost": "0.0030",
+                "id": "6379",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6378",
+                "requestID": "61234567890123470",
+                "time": "2024-01-02T11:00:00.000000000Z"
+            },
+            "relatedTransactionIDs": [
+                "6378",
+                "6379"
+            ],
+            "lastTransactionID": "6379"
+        }
+        "#
+        .to_owned()
+    }
+
+    pub fn set_trade_dependent_orders_response_json() -> String {
+        r#"
+        {
+            "takeProfitOrderCancelTransaction": {
+                "type": "ORDER_CANCEL",
+                "orderID": "6371",
+                "reason": "CLIENT_REQUEST",
+                "id": "6380",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6380",
+                "requestID": "61234567890123471",
+                "time": "2024-01-02T11:05:00.000000000Z"
+            },
+            "stopLossOrderTransaction": {
+                "type": "STOP_LOSS_ORDER",
+                "tradeID": "6369",
+                "price": "1.07000",
+                "timeInForce": "GTC",
+                "triggerCondition": "DEFAULT",
+                "reason": "CLIENT_ORDER",
+                "id": "6381",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6380",
+                "requestID": "61234567890123471",
+                "time": "2024-01-02T11:05:00.000000000Z"
+            },
+            "relatedTransactionIDs": [
+                "6380",
+                "6381"
+            ],
+            "lastTransactionID": "6381"
+        }
+        "#
+        .to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        number::number,
+        oanda::{trade::fixtures::trades_json, CloseTradeUnits, TradeSpecifier, TradeSummary},
+    };
+
+    #[test]
+    fn test_trade_summary_deserialize() {
+        let mut json: serde_json::Value = serde_json::from_str(&trades_json()).unwrap();
+        let trade = json[0].as_object_mut().unwrap();
+        trade.remove("takeProfitOrder");
+        trade.remove("trailingStopLossOrder");
+        trade.insert("takeProfitOrderID".to_string(), "6371".into());
+        trade.insert("trailingStopLossOrderID".to_string(), "6377".into());
+
+        let summary: TradeSummary = serde_json::from_value(json[0].clone()).unwrap();
+
+        assert_eq!("6369", summary.id);
+        assert_eq!(number("100"), summary.current_units);
+        assert_eq!(Some("6371"), summary.orders.take_profit_order_id.as_deref());
+        assert_eq!(None, summary.orders.stop_loss_order_id);
+        assert_eq!(
+            Some("6377"),
+            summary.orders.trailing_stop_loss_order_id.as_deref()
+        );
+
+        let serialized = serde_json::to_value(&summary).unwrap();
+        assert_eq!("6371", serialized["takeProfitOrderID"]);
+        assert_eq!(
+            summary,
+            serde_json::from_value::<TradeSummary>(serialized).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_trade_specifier_to_string() {
+        assert_eq!("6369", TradeSpecifier::Id("6369".to_string()).to_string());
+        assert_eq!(
+            "@my-trade",
+            TradeSpecifier::ClientId("my-trade".to_string()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_trade_specifier_from_str() {
+        assert_eq!(
+            TradeSpecifier::Id("6369".to_string()),
+            "6369".parse().unwrap()
+        );
+        assert_eq!(
+            TradeSpecifier::ClientId("my-trade".to_string()),
+            "@my-trade".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_close_trade_units_serialize() {
+        assert_eq!(
+            r#""ALL""#,
+            serde_json::to_string(&CloseTradeUnits::All).unwrap()
+        );
+        assert_eq!(
+            r#""50""#,
+            serde_json::to_string(&CloseTradeUnits::Units(number("50"))).unwrap()
+        );
+    }
+}
@@ -0,0 +1,1080 @@
+use chrono::{DateTime, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    deserializer::{de_option_string_as_number, de_string_as_number},
+    macros::string_enum,
+    oanda::{
+        ClientExtensions, GuaranteedStopLossDetails, GuaranteedStopLossOrderRequest,
+        LimitOrderRequest, MarketIfTouchedOrderRequest, MarketOrderRequest, OrderPositionFill,
+        StopLossDetails, StopLossOrderRequest, StopOrderRequest, TakeProfitDetails,
+        TakeProfitOrderRequest, TrailingStopLossDetails, TrailingStopLossOrderRequest,
+    },
+    serializer::{ser_number_as_string, ser_option_number_as_string},
+    Number,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Transaction {
+    Create(CreateTransaction),
+    Close(TransactionHeader),
+    Reopen(TransactionHeader),
+    ClientConfigure(ClientConfigureTransaction),
+    ClientConfigureReject(ClientConfigureRejectTransaction),
+    TransferFunds(TransferFundsTransaction),
+    TransferFundsReject(TransferFundsRejectTransaction),
+    MarketOrder(OrderCreateTransaction<MarketOrderRequest>),
+    MarketOrderReject(OrderRejectTransaction<MarketOrderRequest>),
+    LimitOrder(OrderCreateTransaction<LimitOrderRequest>),
+    LimitOrderReject(OrderRejectTransaction<LimitOrderRequest>),
+    StopOrder(OrderCreateTransaction<StopOrderRequest>),
+    StopOrderReject(OrderRejectTransaction<StopOrderRequest>),
+    MarketIfTouchedOrder(OrderCreateTransaction<MarketIfTouchedOrderRequest>),
+    MarketIfTouchedOrderReject(OrderRejectTransaction<MarketIfTouchedOrderRequest>),
+    TakeProfitOrder(OrderCreateTransaction<TakeProfitOrderRequest>),
+    TakeProfitOrderReject(OrderRejectTransaction<TakeProfitOrderRequest>),
+    StopLossOrder(OrderCreateTransaction<StopLossOrderRequest>),
+    StopLossOrderReject(OrderRejectTransaction<StopLossOrderRequest>),
+    GuaranteedStopLossOrder(OrderCreateTransaction<GuaranteedStopLossOrderRequest>),
+    GuaranteedStopLossOrderReject(OrderRejectTransaction<GuaranteedStopLossOrderRequest>),
+    TrailingStopLossOrder(OrderCreateTransaction<TrailingStopLossOrderRequest>),
+    TrailingStopLossOrderReject(OrderRejectTransaction<TrailingStopLossOrderRequest>),
+    FixedPriceOrder(FixedPriceOrderTransaction),
+    OrderFill(OrderFillTransaction),
+    OrderCancel(OrderCancelTransaction),
+    OrderCancelReject(OrderCancelRejectTransaction),
+    OrderClientExtensionsModify(OrderClientExtensionsModifyTransaction),
+    OrderClientExtensionsModifyReject(OrderClientExtensionsModifyRejectTransaction),
+    TradeClientExtensionsModify(TradeClientExtensionsModifyTransaction),
+    TradeClientExtensionsModifyReject(TradeClientExtensionsModifyRejectTransaction),
+    MarginCallEnter(TransactionHeader),
+    MarginCallExtend(MarginCallExtendTransaction),
+    MarginCallExit(TransactionHeader),
+    DelayedTradeClosure(DelayedTradeClosureTransaction),
+    DailyFinancing(DailyFinancingTransaction),
+    DividendAdjustment(DividendAdjustmentTransaction),
+    ResetResettablePl(TransactionHeader),
+    /// A transaction type that isn't modelled yet.
+    #[serde(untagged)]
+    Unknown(UnknownTransaction),
+}
+
+impl Transaction {
+    pub fn header(&self) -> Option<&TransactionHeader> {
+        match self {
+            Transaction::Create(transaction) => Some(&transaction.header),
+            Transaction::Close(header)
+            | Transaction::Reopen(header)
+            | Transaction::MarginCallEnter(header)
+            | Transaction::MarginCallExit(header)
+            | Transaction::ResetResettablePl(header) => Some(header),
+            Transaction::ClientConfigure(transaction) => Some(&transaction.header),
+            Transaction::ClientConfigureReject(transaction) => Some(&transaction.header),
+            Transaction::TransferFunds(transaction) => Some(&transaction.header),
+            Transaction::TransferFundsReject(transaction) => Some(&transaction.header),
+            Transaction::MarketOrder(transaction) => Some(&transaction.header),
+            Transaction::MarketOrderReject(transaction) => Some(&transaction.header),
+            Transaction::LimitOrder(transaction) => Some(&transaction.header),
+            Transaction::LimitOrderReject(transaction) => Some(&transaction.header),
+            Transaction::StopOrder(transaction) => Some(&transaction.header),
+            Transaction::StopOrderReject(transaction) => Some(&transaction.header),
+            Transaction::MarketIfTouchedOrder(transaction) => Some(&transaction.header),
+            Transaction::MarketIfTouchedOrderReject(transaction) => Some(&transaction.header),
+            Transaction::TakeProfitOrder(transaction) => Some(&transaction.header),
+            Transaction::TakeProfitOrderReject(transaction) => Some(&transaction.header),
+            Transaction::StopLossOrder(transaction) => Some(&transaction.header),
+            Transaction::StopLossOrderReject(transaction) => Some(&transaction.header),
+            Transaction::GuaranteedStopLossOrder(transaction) => Some(&transaction.header),
+            Transaction::GuaranteedStopLossOrderReject(transaction) => Some(&transaction.header),
+            Transaction::TrailingStopLossOrder(transaction) => Some(&transaction.header),
+            Transaction::TrailingStopLossOrderReject(transaction) => Some(&transaction.header),
+            Transaction::FixedPriceOrder(transaction) => Some(&transaction.header),
+            Transaction::OrderFill(transaction) => Some(&transaction.header),
+            Transaction::OrderCancel(transaction) => Some(&transaction.header),
+            Transaction::OrderCancelReject(transaction) => Some(&transaction.header),
+            Transaction::OrderClientExtensionsModify(transaction) => Some(&transaction.header),
+            Transaction::OrderClientExtensionsModifyReject(transaction) => {
+                Some(&transaction.header)
+            }
+            Transaction::TradeClientExtensionsModify(transaction) => Some(&transaction.header),
+            Transaction::TradeClientExtensionsModifyReject(transaction) => {
+                Some(&transaction.header)
+            }
+            Transaction::MarginCallExtend(transaction) => Some(&transaction.header),
+            Transaction::DelayedTradeClosure(transaction) => Some(&transaction.header),
+            Transaction::DailyFinancing(transaction) => Some(&transaction.header),
+            Transaction::DividendAdjustment(transaction) => Some(&transaction.header),
+            Transaction::Unknown(_) => None,
+        }
+    }
+
+    pub fn reject_reason(&self) -> Option<&str> {
+        match self {
+            Transaction::ClientConfigureReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::TransferFundsReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::MarketOrderReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::LimitOrderReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::StopOrderReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::MarketIfTouchedOrderReject(transaction) => {
+                transaction.reject_reason.as_deref()
+            }
+            Transaction::TakeProfitOrderReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::StopLossOrderReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::GuaranteedStopLossOrderReject(transaction) => {
+                transaction.reject_reason.as_deref()
+            }
+            Transaction::TrailingStopLossOrderReject(transaction) => {
+                transaction.reject_reason.as_deref()
+            }
+            Transaction::OrderCancelReject(transaction) => transaction.reject_reason.as_deref(),
+            Transaction::OrderClientExtensionsModifyReject(transaction) => {
+                transaction.reject_reason.as_deref()
+            }
+            Transaction::TradeClientExtensionsModifyReject(transaction) => {
+                transaction.reject_reason.as_deref()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Keeps the fields of an unmodelled transaction so it serializes back
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnknownTransaction {
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub id: String,
+    #[serde(flatten)]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHeader {
+    pub id: String,
+    pub time: DateTime<Utc>,
+    #[serde(rename = "userID")]
+    pub user_id: i64,
+    #[serde(rename = "accountID")]
+    pub account_id: String,
+    #[serde(rename = "batchID")]
+    pub batch_id: String,
+    #[serde(rename = "requestID", skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCreateTransaction<T> {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(flatten)]
+    pub order: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(rename = "replacesOrderID", skip_serializing_if = "Option::is_none")]
+    pub replaces_order_id: Option<String>,
+    #[serde(
+        rename = "cancellingTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cancelling_transaction_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_close: Option<MarketOrderTradeClose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub long_position_closeout: Option<MarketOrderPositionCloseout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_position_closeout: Option<MarketOrderPositionCloseout>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketOrderTradeClose {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<String>,
+    pub units: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketOrderPositionCloseout {
+    pub instrument: String,
+    pub units: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRejectTransaction<T> {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(flatten)]
+    pub order: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFillTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "orderID")]
+    pub order_id: String,
+    #[serde(rename = "clientOrderID", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price: Option<Number>,
+    #[serde(
+        rename = "fullVWAP",
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub full_vwap: Option<Number>,
+    pub reason: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub pl: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub financing: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub commission: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub guaranteed_execution_fee: Option<Number>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub account_balance: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_opened: Option<TradeOpen>,
+    #[serde(default)]
+    pub trades_closed: Vec<TradeReduce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_reduced: Option<TradeReduce>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub half_spread_cost: Option<Number>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCancelTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "orderID")]
+    pub order_id: String,
+    #[serde(rename = "clientOrderID", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    pub reason: String,
+    #[serde(rename = "replacedByOrderID", skip_serializing_if = "Option::is_none")]
+    pub replaced_by_order_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCancelRejectTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "orderID")]
+    pub order_id: String,
+    #[serde(rename = "clientOrderID", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderClientExtensionsModifyTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "orderID")]
+    pub order_id: String,
+    #[serde(rename = "clientOrderID", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions_modify: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions_modify: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderClientExtensionsModifyRejectTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "orderID")]
+    pub order_id: String,
+    #[serde(rename = "clientOrderID", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions_modify: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions_modify: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeOpen {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub guaranteed_execution_fee: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub half_spread_cost: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub initial_margin_required: Option<Number>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeReduce {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub price: Option<Number>,
+    #[serde(
+        rename = "realizedPL",
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub realized_pl: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub financing: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub guaranteed_execution_fee: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub half_spread_cost: Option<Number>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "divisionID", skip_serializing_if = "Option::is_none")]
+    pub division_id: Option<i64>,
+    #[serde(rename = "siteID", skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<i64>,
+    #[serde(rename = "accountUserID", skip_serializing_if = "Option::is_none")]
+    pub account_user_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<i64>,
+    pub home_currency: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientConfigureTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub margin_rate: Option<Number>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientConfigureRejectTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub margin_rate: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferFundsTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub amount: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub account_balance: Option<Number>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferFundsRejectTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub amount: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixedPriceOrderTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub units: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub price: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_fill: Option<OrderPositionFill>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeClientExtensionsModifyTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions_modify: Option<ClientExtensions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeClientExtensionsModifyRejectTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions_modify: Option<ClientExtensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCallExtendTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    pub extension_number: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelayedTradeClosureTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(rename = "tradeIDs", skip_serializing_if = "Option::is_none")]
+    pub trade_ids: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyFinancingTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub financing: Number,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub account_balance: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_financing_mode: Option<String>,
+    #[serde(default)]
+    pub position_financings: Vec<PositionFinancing>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionFinancing {
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub financing: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub base_financing: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub quote_financing: Option<Number>,
+    #[serde(default)]
+    pub open_trade_financings: Vec<OpenTradeFinancing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_financing_mode: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenTradeFinancing {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub financing: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub base_financing: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub quote_financing: Option<Number>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub financing_rate: Option<Number>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DividendAdjustmentTransaction {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+    pub instrument: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub dividend_adjustment: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub quote_dividend_adjustment: Option<Number>,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub account_balance: Number,
+    #[serde(default)]
+    pub open_trade_dividend_adjustments: Vec<OpenTradeDividendAdjustment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenTradeDividendAdjustment {
+    #[serde(rename = "tradeID")]
+    pub trade_id: String,
+    #[serde(
+        serialize_with = "ser_number_as_string",
+        deserialize_with = "de_string_as_number"
+    )]
+    pub dividend_adjustment: Number,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser_option_number_as_string",
+        deserialize_with = "de_option_string_as_number"
+    )]
+    pub quote_dividend_adjustment: Option<Number>,
+}
+
+string_enum! {
+    pub enum TransactionFilter {
+        Order = "ORDER",
+        Funding = "FUNDING",
+        Admin = "ADMIN",
+        Create = "CREATE",
+        Close = "CLOSE",
+        Reopen = "REOPEN",
+        ClientConfigure = "CLIENT_CONFIGURE",
+        ClientConfigureReject = "CLIENT_CONFIGURE_REJECT",
+        TransferFunds = "TRANSFER_FUNDS",
+        TransferFundsReject = "TRANSFER_FUNDS_REJECT",
+        MarketOrder = "MARKET_ORDER",
+        MarketOrderReject = "MARKET_ORDER_REJECT",
+        LimitOrder = "LIMIT_ORDER",
+        LimitOrderReject = "LIMIT_ORDER_REJECT",
+        StopOrder = "STOP_ORDER",
+        StopOrderReject = "STOP_ORDER_REJECT",
+        MarketIfTouchedOrder = "MARKET_IF_TOUCHED_ORDER",
+        MarketIfTouchedOrderReject = "MARKET_IF_TOUCHED_ORDER_REJECT",
+        TakeProfitOrder = "TAKE_PROFIT_ORDER",
+        TakeProfitOrderReject = "TAKE_PROFIT_ORDER_REJECT",
+        StopLossOrder = "STOP_LOSS_ORDER",
+        StopLossOrderReject = "STOP_LOSS_ORDER_REJECT",
+        GuaranteedStopLossOrder = "GUARANTEED_STOP_LOSS_ORDER",
+        GuaranteedStopLossOrderReject = "GUARANTEED_STOP_LOSS_ORDER_REJECT",
+        TrailingStopLossOrder = "TRAILING_STOP_LOSS_ORDER",
+        TrailingStopLossOrderReject = "TRAILING_STOP_LOSS_ORDER_REJECT",
+        FixedPriceOrder = "FIXED_PRICE_ORDER",
+        OneCancelsAllOrder = "ONE_CANCELS_ALL_ORDER",
+        OneCancelsAllOrderReject = "ONE_CANCELS_ALL_ORDER_REJECT",
+        OneCancelsAllOrderTriggered = "ONE_CANCELS_ALL_ORDER_TRIGGERED",
+        OrderFill = "ORDER_FILL",
+        OrderCancel = "ORDER_CANCEL",
+        OrderCancelReject = "ORDER_CANCEL_REJECT",
+        OrderClientExtensionsModify = "ORDER_CLIENT_EXTENSIONS_MODIFY",
+        OrderClientExtensionsModifyReject = "ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT",
+        TradeClientExtensionsModify = "TRADE_CLIENT_EXTENSIONS_MODIFY",
+        TradeClientExtensionsModifyReject = "TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT",
+        MarginCallEnter = "MARGIN_CALL_ENTER",
+        MarginCallExtend = "MARGIN_CALL_EXTEND",
+        MarginCallExit = "MARGIN_CALL_EXIT",
+        DelayedTradeClosure = "DELAYED_TRADE_CLOSURE",
+        DailyFinancing = "DAILY_FINANCING",
+        DividendAdjustment = "DIVIDEND_ADJUSTMENT",
+        ResetResettablePl = "RESET_RESETTABLE_PL",
+    }
+}
+
+fn ser_transaction_filters<S>(
+    filters: &[TransactionFilter],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let filters: Vec<_> = filters.iter().map(TransactionFilter::as_str).collect();
+
+    serializer.serialize_str(&filters.join(","))
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<u32>,
+    #[serde(
+        rename = "type",
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "ser_transaction_filters"
+    )]
+    pub types: Vec<TransactionFilter>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct TransactionsIdRangeParams {
+    pub from: String,
+    pub to: String,
+    #[serde(
+        rename = "type",
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "ser_transaction_filters"
+    )]
+    pub types: Vec<TransactionFilter>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct TransactionsSinceIdParams {
+    pub id: String,
+    #[serde(
+        rename = "type",
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "ser_transaction_filters"
+    )]
+    pub types: Vec<TransactionFilter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPages {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub page_size: u32,
+    #[serde(rename = "type", default)]
+    pub types: Vec<TransactionFilter>,
+    pub count: u64,
+    pub pages: Vec<String>,
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHeartbeat {
+    #[serde(rename = "lastTransactionID")]
+    pub last_transaction_id: String,
+    pub time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionStreamMessage {
+    Transaction(Box<Transaction>),
+    Heartbeat(TransactionHeartbeat),
+}
+
+impl<'de> Deserialize<'de> for TransactionStreamMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Heartbeats share the "type" tag with transactions, so they would
+        // otherwise be swallowed by `Transaction::Unknown`.
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        if value.get("type").and_then(serde_json::Value::as_str) == Some("HEARTBEAT") {
+            serde_json::from_value(value)
+                .map(TransactionStreamMessage::Heartbeat)
+                .map_err(de::Error::custom)
+        } else {
+            serde_json::from_value(value)
+                .map(|transaction| TransactionStreamMessage::Transaction(Box::new(transaction)))
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    pub fn transactions_json() -> String {
+        r#"
+        [
+            {
+                "type": "CREATE",
+                "divisionID": 4,
+                "siteID": 101,
+                "accountUserID": 1234567,
+                "accountNumber": 1,
+                "homeCurrency": "USD",
+                "id": "1",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "1",
+                "time": "2023-06-01T12:00:00.000000000Z"
+            },
+            {
+                "type": "TRANSFER_FUNDS",
+                "amount": "100000.0000",
+                "fundingReason": "CLIENT_FUNDING",
+                "accountBalance": "100000.0000",
+                "id": "2",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "2",
+                "time": "2023-06-01T12:00:01.000000000Z"
+            },
+            {
+                "type": "DAILY_FINANCING",
+                "financing": "-0.0012",
+                "accountBalance": "99993.1474",
+                "accountFinancingMode": "DAILY",
+                "positionFinancings": [
+                    {
+                        "instrument": "EUR_USD",
+                        "financing": "-0.0012",
+                        "openTradeFinancings": [
+                            {
+                                "tradeID": "6369",
+                                "financing": "-0.0012",
+                                "financingRate": "-0.0432"
+                            }
+                        ]
+                    }
+                ],
+                "id": "6380",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6380",
+                "time": "2024-01-02T21:00:00.000000000Z"
+            },
+            {
+                "type": "MARGIN_CALL_ENTER",
+                "id": "6381",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6381",
+                "time": "2024-01-02T22:00:00.000000000Z"
+            },
+            {
+                "type": "SOME_FUTURE_TRANSACTION",
+                "id": "6382",
+                "accountID": "101-004-1234567-001",
+                "userID": 1234567,
+                "batchID": "6382",
+                "time": "2024-01-02T23:00:00.000000000Z"
+            }
+        ]
+        "#
+        .to_owned()
+    }
+
+    pub fn transaction_pages_json() -> String {
+        r#"
+        {
+            "from": "2024-01-01T00:00:00.000000000Z",
+            "to": "2024-01-03T00:00:00.000000000Z",
+            "pageSize": 100,
+            "type": [
+                "ORDER_FILL",
+                "DAILY_FINANCING"
+            ],
+            "count": 2,
+            "pages": [
+                "https://api-fxpractice.oanda.com/v3/accounts/101-004-1234567-001/transactions/idrange?from=6380&to=6383"
+            ],
+            "lastTransactionID": "6383"
+        }
+        "#
+        .to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        number::number,
+        oanda::{
+            transaction::fixtures::{transaction_pages_json, transactions_json},
+            Transaction, TransactionFilter, TransactionPages, TransactionStreamMessage,
+        },
+    };
+
+    #[test]
+    fn test_transaction_pages_unknown_filter() {
+        let mut json: serde_json::Value = serde_json::from_str(&transaction_pages_json()).unwrap();
+        json["type"] = serde_json::json!(["DIVIDEND_ADJUSTMENT", "SOME_FUTURE_FILTER"]);
+
+        let pages: TransactionPages = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(
+            vec![
+                TransactionFilter::DividendAdjustment,
+                TransactionFilter::Unknown("SOME_FUTURE_FILTER".to_string())
+            ],
+            pages.types
+        );
+        assert_eq!(json["type"], serde_json::to_value(&pages).unwrap()["type"]);
+    }
+
+    #[test]
+    fn test_transactions_deserialize() {
+        let transactions: Vec<Transaction> = serde_json::from_str(&transactions_json()).unwrap();
+
+        assert!(matches!(
+            transactions[0],
+            Transaction::Create(ref transaction) if transaction.home_currency == "USD"
+        ));
+        assert!(matches!(
+            transactions[1],
+            Transaction::TransferFunds(ref transaction) if transaction.amount == number("100000")
+        ));
+        assert!(matches!(
+            transactions[2],
+            Transaction::DailyFinancing(ref transaction)
+                if transaction.position_financings[0].open_trade_financings.len() == 1
+        ));
+        assert!(matches!(transactions[3], Transaction::MarginCallEnter(_)));
+        assert!(matches!(
+            transactions[4],
+            Transaction::Unknown(ref transaction)
+                if transaction.transaction_type == "SOME_FUTURE_TRANSACTION"
+                    && transaction.id == "6382"
+        ));
+
+        assert_eq!("6381", transactions[3].header().unwrap().id);
+        assert!(transactions[4].header().is_none());
+    }
+
+    #[test]
+    fn test_transactions_unknown_round_trip() {
+        let mut json: serde_json::Value = serde_json::from_str(&transactions_json()).unwrap();
+        json[4]["someFutureField"] = serde_json::json!({"units": "100.000"});
+
+        let transaction: Transaction = serde_json::from_value(json[4].clone()).unwrap();
+
+        assert!(matches!(transaction, Transaction::Unknown(_)));
+        assert_eq!(json[4], serde_json::to_value(&transaction).unwrap());
+    }
+
+    #[test]
+    fn test_transaction_stream_message_deserialize() {
+        let heartbeat: TransactionStreamMessage = serde_json::from_str(
+            r#"{"type":"HEARTBEAT","lastTransactionID":"6383","time":"2024-01-02T23:00:00.000000000Z"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            heartbeat,
+            TransactionStreamMessage::Heartbeat(ref heartbeat)
+                if heartbeat.last_transaction_id == "6383"
+        ));
+
+        let transaction: TransactionStreamMessage = serde_json::from_str(
+            r#"{"type":"MARGIN_CALL_EXIT","id":"6384","accountID":"101-004-1234567-001","userID":1234567,"batchID":"6384","time":"2024-01-02T23:00:00.000000000Z"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            transaction,
+            TransactionStreamMessage::Transaction(ref transaction)
+                if matches!(**transaction, Transaction::MarginCallExit(_))
+        ));
+    }
+}
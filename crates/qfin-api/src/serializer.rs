@@ -0,0 +1,65 @@
+use serde::Serializer;
+
+use crate::Number;
+
+pub(crate) fn ser_number_as_string<S>(value: &Number, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
+/// Serializes a number as a JSON number, for fields such as liquidity that the
+/// API sends unquoted. Integral values are written without a fractional part.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn ser_number_as_number<S>(value: &Number, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+    if value.fract() == 0.0 && value.abs() < MAX_SAFE_INTEGER {
+        serializer.serialize_i64(*value as i64)
+    } else {
+        serializer.serialize_f64(*value)
+    }
+}
+
+/// Serializes a number as a JSON number, for fields such as liquidity that the
+/// API sends unquoted. Integral values are written without a fractional part.
+#[cfg(feature = "decimal")]
+pub(crate) fn ser_number_as_number<S>(value: &Number, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    use rust_decimal::prelude::ToPrimitive;
+    use serde::ser::Error;
+
+    match value.to_i64() {
+        Some(integer) if value.is_integer() => serializer.serialize_i64(integer),
+        _ => match value.to_f64() {
+            Some(float) => serializer.serialize_f64(float),
+            None => Err(S::Error::custom(format!("number out of range: {value}"))),
+        },
+    }
+}
+
+pub(crate) fn ser_option_number_as_string<S>(
+    value: &Option<Number>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => ser_number_as_string(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub(crate) fn ser_comma_separated<S>(values: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&values.join(","))
+}
@@ -0,0 +1,397 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use qfin_api::{
+    oanda::{
+        ClientPrice, CurrencyCode, CurrencyPair, HomeConversions, Instrument, InstrumentName,
+        Pricing,
+    },
+    Number,
+};
+
+/// Converts amounts into an account's home currency.
+///
+/// OANDA's pricing home conversions are used when available, otherwise the
+/// factor is derived from mid prices of the known pairs, directly, inverted or
+/// triangulated through other currencies (e.g. `TRY -> USD` via `USD_TRY`, or
+/// `TRY -> JPY -> USD` via `TRY_JPY` and `USD_JPY`).
+#[derive(Debug, Clone)]
+pub struct CurrencyConverter {
+    home: CurrencyCode,
+    home_conversions: HashMap<CurrencyCode, Number>,
+    rates: BTreeMap<CurrencyPair, Number>,
+    instrument_pairs: HashMap<String, Option<CurrencyPair>>,
+}
+
+impl CurrencyConverter {
+    pub fn new(home: CurrencyCode) -> Self {
+        CurrencyConverter {
+            home,
+            home_conversions: HashMap::new(),
+            rates: BTreeMap::new(),
+            instrument_pairs: HashMap::new(),
+        }
+    }
+
+    pub fn from_pricing(home: CurrencyCode, pricing: &Pricing) -> Self {
+        let mut converter = CurrencyConverter::new(home);
+        for price in &pricing.prices {
+            converter.update_price(price);
+        }
+        for conversions in pricing.home_conversions.iter().flatten() {
+            converter.update_home_conversions(conversions);
+        }
+        converter
+    }
+
+    pub fn home(&self) -> CurrencyCode {
+        self.home
+    }
+
+    /// Sets the factor that converts one unit of `currency` into the home
+    /// currency.
+    pub fn add_home_conversion(&mut self, currency: CurrencyCode, factor: Number) {
+        self.home_conversions.insert(currency, factor);
+    }
+
+    /// Sets the price of one unit of `pair.base` in `pair.quote`.
+    pub fn add_rate(&mut self, pair: CurrencyPair, rate: Number) {
+        self.rates.insert(pair, rate);
+    }
+
+    /// Registers the type of each instrument, so that [`update_price`]
+    /// only records prices of currency and metal instruments.
+    ///
+    /// [`update_price`]: CurrencyConverter::update_price
+    pub fn add_instruments<'a>(&mut self, instruments: impl IntoIterator<Item = &'a Instrument>) {
+        for instrument in instruments {
+            self.instrument_pairs
+                .insert(instrument.name.clone(), instrument.currency_pair());
+        }
+    }
+
+    /// Records the mid price of a currency pair.
+    ///
+    /// Instruments registered with [`add_instruments`] are classified by their
+    /// type, so CFDs such as `BCO_USD` are ignored. Any other instrument whose
+    /// name looks like a pair of currency codes is taken to be a currency pair.
+    ///
+    /// [`add_instruments`]: CurrencyConverter::add_instruments
+    pub fn update_price(&mut self, price: &ClientPrice) {
+        let pair = match self.instrument_pairs.get(&price.instrument) {
+            Some(pair) => *pair,
+            None => price.instrument.parse().ok(),
+        };
+
+        if let Some(pair) = pair {
+            let mid = (price.closeout_bid + price.closeout_ask) / Number::from(2u8);
+            self.add_rate(pair, mid);
+        }
+    }
+
+    /// Records the position value conversion factor from a pricing response.
+    /// Unparseable currencies are ignored.
+    pub fn update_home_conversions(&mut self, conversions: &HomeConversions) {
+        if let Ok(currency) = conversions.currency.parse() {
+            self.add_home_conversion(currency, conversions.position_value);
+        }
+    }
+
+    /// Returns the factor that converts one unit of `from` into the home
+    /// currency, or `None` if no chain of known rates connects them.
+    pub fn conversion_factor(&self, from: CurrencyCode) -> Option<Number> {
+        if from == self.home {
+            return Some(Number::from(1u8));
+        }
+
+        if let Some(factor) = self.home_conversions.get(&from) {
+            return Some(*factor);
+        }
+
+        self.triangulate(from)
+    }
+
+    pub fn convert(&self, amount: Number, from: CurrencyCode) -> Option<Number> {
+        Some(amount * self.conversion_factor(from)?)
+    }
+
+    /// Returns the home currency value of a position of `units` in
+    /// `instrument` at `price`, which is quoted in the instrument's quote
+    /// currency.
+    pub fn position_value(
+        &self,
+        instrument: &InstrumentName,
+        units: Number,
+        price: Number,
+    ) -> Option<Number> {
+        self.convert(units.abs() * price, instrument.quote)
+    }
+
+    fn triangulate(&self, from: CurrencyCode) -> Option<Number> {
+        let zero = Number::default();
+        let mut visited = HashSet::from([from]);
+        let mut queue = VecDeque::from([(from, Number::from(1u8))]);
+
+        // Breadth first so the fewest conversions, and least rounding, wins.
+        // Rates are visited in pair order, so ties between equally short
+        // paths always resolve the same way.
+        while let Some((currency, factor)) = queue.pop_front() {
+            for (pair, rate) in &self.rates {
+                if *rate <= zero {
+                    continue;
+                }
+
+                let next = if pair.base == currency {
+                    (pair.quote, factor * *rate)
+                } else if pair.quote == currency {
+                    (pair.base, factor / *rate)
+                } else {
+                    continue;
+                };
+
+                if next.0 == self.home {
+                    return Some(next.1);
+                }
+
+                if let Some(home_factor) = self.home_conversions.get(&next.0) {
+                    return Some(next.1 * *home_factor);
+                }
+
+                if visited.insert(next.0) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qfin_api::{
+        number::round_dp,
+        oanda::{ClientPrice, CurrencyCode, CurrencyPair, Instrument, Pricing},
+    };
+
+    use crate::{conversion::CurrencyConverter, number};
+
+    fn currency(code: &str) -> CurrencyCode {
+        code.parse().unwrap()
+    }
+
+    fn pair(name: &str) -> CurrencyPair {
+        name.parse().unwrap()
+    }
+
+    fn instrument(name: &str, instrument_type: &str) -> Instrument {
+        serde_json::from_str(&format!(
+            r#"
+            {{
+                "name": "{name}",
+                "type": "{instrument_type}",
+                "displayName": "{name}",
+                "pipLocation": -4,
+                "displayPrecision": 5,
+                "tradeUnitsPrecision": 0,
+                "minimumTradeSize": "1",
+                "maximumTrailingStopDistance": "1.00000",
+                "minimumTrailingStopDistance": "0.00050",
+                "maximumPositionSize": "0",
+                "maximumOrderUnits": "100000",
+                "marginRate": "0.05",
+                "guaranteedStopLossOrderMode": "DISABLED",
+                "tags": [],
+                "financing": {{
+                    "longRate": "0",
+                    "shortRate": "0",
+                    "financingDaysOfWeek": []
+                }}
+            }}
+            "#
+        ))
+        .unwrap()
+    }
+
+    fn price(instrument: &str, bid: &str, ask: &str) -> ClientPrice {
+        serde_json::from_str(&format!(
+            r#"
+            {{
+                "type": "PRICE",
+                "instrument": "{instrument}",
+                "time": "2024-01-02T10:00:01.000000000Z",
+                "tradeable": true,
+                "bids": [],
+                "asks": [],
+                "closeoutBid": "{bid}",
+                "closeoutAsk": "{ask}"
+            }}
+            "#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_conversion_factor_home() {
+        let converter = CurrencyConverter::new(currency("USD"));
+
+        assert_eq!(
+            Some(number("1")),
+            converter.conversion_factor(currency("USD"))
+        );
+        assert_eq!(None, converter.conversion_factor(currency("EUR")));
+    }
+
+    #[test]
+    fn test_conversion_factor_direct_and_inverse() {
+        let mut converter = CurrencyConverter::new(currency("USD"));
+        converter.add_rate(pair("EUR_USD"), number("1.25"));
+        converter.add_rate(pair("USD_JPY"), number("160"));
+
+        assert_eq!(
+            Some(number("1.25")),
+            converter.conversion_factor(currency("EUR"))
+        );
+        assert_eq!(
+            Some(number("0.00625")),
+            converter
+                .conversion_factor(currency("JPY"))
+                .map(|factor| round_dp(factor, 5))
+        );
+    }
+
+    #[test]
+    fn test_conversion_factor_cross() {
+        let mut converter = CurrencyConverter::new(currency("USD"));
+        converter.add_rate(pair("TRY_JPY"), number("4.5"));
+        converter.add_rate(pair("USD_JPY"), number("160"));
+
+        let factor = converter.conversion_factor(currency("TRY")).unwrap();
+        assert_eq!(number("0.028125"), round_dp(factor, 6));
+    }
+
+    #[test]
+    fn test_conversion_factor_competing_cross_paths() {
+        // TRY reaches USD through EUR or through JPY, at slightly different
+        // rates.
+        let rates = [
+            (pair("USD_JPY"), number("160")),
+            (pair("TRY_JPY"), number("4.5")),
+            (pair("EUR_USD"), number("1.25")),
+            (pair("EUR_TRY"), number("45")),
+        ];
+
+        let factors: Vec<_> = (0..rates.len())
+            .map(|start| {
+                let mut converter = CurrencyConverter::new(currency("USD"));
+                for (pair, rate) in rates.iter().cycle().skip(start).take(rates.len()) {
+                    converter.add_rate(*pair, *rate);
+                }
+                converter.conversion_factor(currency("TRY")).unwrap()
+            })
+            .collect();
+
+        // EUR_TRY sorts first, so the path through EUR wins every time.
+        let want = number("1") / number("45") * number("1.25");
+        assert!(factors.iter().all(|factor| *factor == want));
+    }
+
+    #[test]
+    fn test_conversion_factor_prefers_home_conversions() {
+        let mut converter = CurrencyConverter::new(currency("USD"));
+        converter.add_rate(pair("EUR_USD"), number("1.25"));
+        converter.add_home_conversion(currency("EUR"), number("1.2"));
+
+        assert_eq!(
+            Some(number("1.2")),
+            converter.conversion_factor(currency("EUR"))
+        );
+    }
+
+    #[test]
+    fn test_position_value() {
+        let mut converter = CurrencyConverter::new(currency("USD"));
+        converter.add_rate(pair("USD_JPY"), number("160"));
+
+        let value = converter
+            .position_value(&"TRY_JPY".parse().unwrap(), number("-1000"), number("4.5"))
+            .unwrap();
+        assert_eq!(number("28.125"), round_dp(value, 5));
+    }
+
+    #[test]
+    fn test_from_pricing() {
+        let pricing: Pricing = serde_json::from_str(
+            r#"
+            {
+                "prices": [
+                    {
+                        "type": "PRICE",
+                        "instrument": "GBP_USD",
+                        "time": "2024-01-02T10:00:01.000000000Z",
+                        "tradeable": true,
+                        "bids": [],
+                        "asks": [],
+                        "closeoutBid": "1.2",
+                        "closeoutAsk": "1.3"
+                    },
+                    {
+                        "type": "PRICE",
+                        "instrument": "SPX500_USD",
+                        "time": "2024-01-02T10:00:01.000000000Z",
+                        "tradeable": true,
+                        "bids": [],
+                        "asks": [],
+                        "closeoutBid": "4700",
+                        "closeoutAsk": "4701"
+                    }
+                ],
+                "homeConversions": [
+                    {
+                        "currency": "EUR",
+                        "accountGain": "1.08402",
+                        "accountLoss": "1.08390",
+                        "positionValue": "1.08396"
+                    }
+                ],
+                "time": "2024-01-02T10:00:02.000000000Z"
+            }
+            "#,
+        )
+        .unwrap();
+
+        let converter = CurrencyConverter::from_pricing(currency("USD"), &pricing);
+
+        assert_eq!(
+            Some(number("1.08396")),
+            converter.conversion_factor(currency("EUR"))
+        );
+        assert_eq!(
+            Some(number("1.25")),
+            converter.conversion_factor(currency("GBP"))
+        );
+    }
+
+    #[test]
+    fn test_update_price_skips_cfds() {
+        let mut converter = CurrencyConverter::new(currency("USD"));
+        converter.add_instruments(&[
+            instrument("BCO_USD", "CFD"),
+            instrument("XAU_USD", "METAL"),
+            instrument("EUR_USD", "CURRENCY"),
+        ]);
+
+        converter.update_price(&price("BCO_USD", "80", "80.1"));
+        converter.update_price(&price("XAU_USD", "2000", "2001"));
+        converter.update_price(&price("EUR_USD", "1.2", "1.3"));
+
+        assert_eq!(None, converter.conversion_factor(currency("BCO")));
+        assert_eq!(
+            Some(number("2000.5")),
+            converter.conversion_factor(currency("XAU"))
+        );
+        assert_eq!(
+            Some(number("1.25")),
+            converter.conversion_factor(currency("EUR"))
+        );
+    }
+}
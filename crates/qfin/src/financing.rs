@@ -0,0 +1,203 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use qfin_api::{
+    oanda::{DayOfWeek, InstrumentFinancing},
+    Number,
+};
+
+/// OANDA divides the annual financing rate over a 365 day year.
+const DAYS_IN_YEAR: u16 = 365;
+
+/// Projects the overnight financing of a position from an instrument's
+/// financing rates.
+///
+/// Amounts are in the instrument's quote currency; positive amounts are paid
+/// to the account and negative amounts are charged. Use
+/// [`CurrencyConverter`](crate::conversion::CurrencyConverter) to convert them
+/// into the home currency.
+#[derive(Debug, Clone, Copy)]
+pub struct FinancingCalculator<'a> {
+    financing: &'a InstrumentFinancing,
+}
+
+impl<'a> FinancingCalculator<'a> {
+    pub fn new(financing: &'a InstrumentFinancing) -> Self {
+        FinancingCalculator { financing }
+    }
+
+    /// Returns the annual rate for a position of `units`, using the long rate
+    /// for positive units and the short rate for negative units.
+    pub fn rate(&self, units: Number) -> Number {
+        if units < Number::default() {
+            self.financing.short_rate
+        } else {
+            self.financing.long_rate
+        }
+    }
+
+    /// Returns how many days are charged at the rollover on `weekday`, e.g.
+    /// three on Wednesdays for most currency pairs to cover the weekend.
+    /// Weekdays missing from the instrument's financing are charged once.
+    pub fn days_charged(&self, weekday: Weekday) -> i32 {
+        let day_of_week = day_of_week(weekday);
+        self.financing
+            .financing_days_of_week
+            .iter()
+            .find(|day| day.day_of_week == day_of_week)
+            .map_or(1, |day| day.days_charged.into())
+    }
+
+    /// Returns the number of days charged for the rollovers on each date in
+    /// `from..to`.
+    pub fn days_charged_between(&self, from: NaiveDate, to: NaiveDate) -> i32 {
+        from.iter_days()
+            .take_while(|date| *date < to)
+            .map(|date| self.days_charged(date.weekday()))
+            .sum()
+    }
+
+    /// Returns the financing for a single charged day of a position of
+    /// `units` valued at `price`.
+    pub fn daily_financing(&self, units: Number, price: Number) -> Number {
+        units.abs() * price * self.rate(units) / Number::from(DAYS_IN_YEAR)
+    }
+
+    /// Returns the financing for holding a position of `units` valued at
+    /// `price` over the rollovers on each date in `from..to`.
+    pub fn financing(
+        &self,
+        units: Number,
+        price: Number,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Number {
+        self.daily_financing(units, price) * Number::from(self.days_charged_between(from, to))
+    }
+}
+
+fn day_of_week(weekday: Weekday) -> DayOfWeek {
+    match weekday {
+        Weekday::Mon => DayOfWeek::Monday,
+        Weekday::Tue => DayOfWeek::Tuesday,
+        Weekday::Wed => DayOfWeek::Wednesday,
+        Weekday::Thu => DayOfWeek::Thursday,
+        Weekday::Fri => DayOfWeek::Friday,
+        Weekday::Sat => DayOfWeek::Saturday,
+        Weekday::Sun => DayOfWeek::Sunday,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday};
+    use qfin_api::{
+        number::round_dp,
+        oanda::{DayOfWeek, FinancingDayOfWeek, InstrumentFinancing},
+    };
+
+    use crate::{financing::FinancingCalculator, number};
+
+    fn financing() -> InstrumentFinancing {
+        let days_charged = [
+            (DayOfWeek::Monday, 1),
+            (DayOfWeek::Tuesday, 1),
+            (DayOfWeek::Wednesday, 3),
+            (DayOfWeek::Thursday, 1),
+            (DayOfWeek::Friday, 1),
+            (DayOfWeek::Saturday, 0),
+            (DayOfWeek::Sunday, 0),
+        ];
+
+        InstrumentFinancing {
+            long_rate: number("-0.0365"),
+            short_rate: number("0.0146"),
+            financing_days_of_week: days_charged
+                .into_iter()
+                .map(|(day_of_week, days_charged)| FinancingDayOfWeek {
+                    day_of_week,
+                    days_charged,
+                })
+                .collect(),
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_days_charged() {
+        let financing = financing();
+        let calculator = FinancingCalculator::new(&financing);
+
+        assert_eq!(3, calculator.days_charged(Weekday::Wed));
+        assert_eq!(0, calculator.days_charged(Weekday::Sun));
+
+        let financing = InstrumentFinancing {
+            financing_days_of_week: Vec::new(),
+            ..financing
+        };
+        assert_eq!(
+            1,
+            FinancingCalculator::new(&financing).days_charged(Weekday::Sun)
+        );
+    }
+
+    #[test]
+    fn test_days_charged_between() {
+        let financing = financing();
+        let calculator = FinancingCalculator::new(&financing);
+
+        // 2024-01-01 is a Monday.
+        assert_eq!(
+            7,
+            calculator.days_charged_between(date(2024, 1, 1), date(2024, 1, 8))
+        );
+        assert_eq!(
+            3,
+            calculator.days_charged_between(date(2024, 1, 3), date(2024, 1, 4))
+        );
+        assert_eq!(
+            0,
+            calculator.days_charged_between(date(2024, 1, 6), date(2024, 1, 8))
+        );
+        assert_eq!(
+            0,
+            calculator.days_charged_between(date(2024, 1, 8), date(2024, 1, 1))
+        );
+    }
+
+    #[test]
+    fn test_daily_financing() {
+        let financing = financing();
+        let calculator = FinancingCalculator::new(&financing);
+
+        assert_eq!(
+            number("-1.25"),
+            round_dp(
+                calculator.daily_financing(number("10000"), number("1.25")),
+                5
+            )
+        );
+        assert_eq!(
+            number("0.5"),
+            round_dp(
+                calculator.daily_financing(number("-10000"), number("1.25")),
+                5
+            )
+        );
+    }
+
+    #[test]
+    fn test_financing() {
+        let financing = financing();
+        let calculator = FinancingCalculator::new(&financing);
+
+        let cost = calculator.financing(
+            number("10000"),
+            number("1.25"),
+            date(2024, 1, 1),
+            date(2024, 1, 8),
+        );
+        assert_eq!(number("-8.75"), round_dp(cost, 5));
+    }
+}
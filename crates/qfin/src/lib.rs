@@ -1,3 +1,12 @@
 pub mod api {
     pub use qfin_api::*;
 }
+
+pub mod conversion;
+pub mod financing;
+pub mod margin;
+
+#[cfg(test)]
+pub(crate) fn number(value: &str) -> api::Number {
+    value.parse().unwrap()
+}
@@ -0,0 +1,277 @@
+use qfin_api::{
+    oanda::{AccountSummary, Instrument},
+    Number,
+};
+
+use crate::conversion::CurrencyConverter;
+
+/// Computes margin requirements for orders in the converter's home currency.
+#[derive(Debug, Clone, Copy)]
+pub struct MarginCalculator<'a> {
+    converter: &'a CurrencyConverter,
+    account_margin_rate: Option<Number>,
+}
+
+impl<'a> MarginCalculator<'a> {
+    pub fn new(converter: &'a CurrencyConverter) -> Self {
+        MarginCalculator {
+            converter,
+            account_margin_rate: None,
+        }
+    }
+
+    /// Applies the account's margin rate, which OANDA uses instead of the
+    /// instrument's whenever it is higher (i.e. the account allows less
+    /// leverage).
+    pub fn with_account_margin_rate(mut self, margin_rate: Number) -> Self {
+        self.account_margin_rate = Some(margin_rate);
+        self
+    }
+
+    pub fn margin_rate(&self, instrument: &Instrument) -> Number {
+        match self.account_margin_rate {
+            Some(rate) if rate > instrument.margin_rate => rate,
+            _ => instrument.margin_rate,
+        }
+    }
+
+    /// Returns the maximum leverage, e.g. 50 for a margin rate of 0.02.
+    pub fn leverage(&self, instrument: &Instrument) -> Option<Number> {
+        let margin_rate = self.margin_rate(instrument);
+        if margin_rate <= Number::default() {
+            return None;
+        }
+        Some(Number::from(1u8) / margin_rate)
+    }
+
+    /// Returns the margin required to hold `units` of `instrument` at
+    /// `price`, or `None` if the quote currency can't be converted.
+    pub fn required_margin(
+        &self,
+        instrument: &Instrument,
+        units: Number,
+        price: Number,
+    ) -> Option<Number> {
+        let value =
+            self.converter
+                .position_value(&instrument.instrument_name().ok()?, units, price)?;
+        Some(value * self.margin_rate(instrument))
+    }
+
+    pub fn has_sufficient_margin(
+        &self,
+        instrument: &Instrument,
+        units: Number,
+        price: Number,
+        margin_available: Number,
+    ) -> Option<bool> {
+        Some(self.required_margin(instrument, units, price)? <= margin_available)
+    }
+
+    /// Returns the most units of `instrument` at `price` that
+    /// `margin_available` can hold, rounded down to the instrument's unit
+    /// precision and capped at its maximum order size. Returns zero when it's
+    /// below the minimum trade size.
+    pub fn max_units(
+        &self,
+        instrument: &Instrument,
+        price: Number,
+        margin_available: Number,
+    ) -> Option<Number> {
+        let zero = Number::default();
+        let margin_per_unit = self.required_margin(instrument, Number::from(1u8), price)?;
+        if margin_per_unit <= zero || margin_available <= zero {
+            return Some(zero);
+        }
+
+        let units = instrument.round_units(margin_available / margin_per_unit);
+        if units < instrument.minimum_trade_size {
+            Some(zero)
+        } else if units > instrument.maximum_order_units {
+            Some(instrument.maximum_order_units)
+        } else {
+            Some(units)
+        }
+    }
+}
+
+/// An account's position relative to a margin closeout. OANDA closes out all
+/// open positions once the margin closeout NAV falls to half of the margin
+/// closeout margin used, i.e. the margin closeout percent reaches 100%.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarginCloseout {
+    pub nav: Number,
+    pub margin_used: Number,
+}
+
+impl MarginCloseout {
+    pub fn new(nav: Number, margin_used: Number) -> Self {
+        MarginCloseout { nav, margin_used }
+    }
+
+    /// Returns the margin closeout percent as a fraction, where 1 means
+    /// closeout. Returns `None` if the NAV is not positive.
+    pub fn percent(&self) -> Option<Number> {
+        if self.nav <= Number::default() {
+            return None;
+        }
+        Some(self.margin_used / (Number::from(2u8) * self.nav))
+    }
+
+    /// Returns the NAV at which the account is closed out.
+    pub fn closeout_nav(&self) -> Number {
+        self.margin_used / Number::from(2u8)
+    }
+
+    /// Returns how much the NAV can fall before the account is closed out.
+    pub fn buffer(&self) -> Number {
+        self.nav - self.closeout_nav()
+    }
+
+    pub fn is_closeout(&self) -> bool {
+        self.margin_used > Number::default() && self.nav <= self.closeout_nav()
+    }
+}
+
+impl From<&AccountSummary> for MarginCloseout {
+    fn from(summary: &AccountSummary) -> Self {
+        MarginCloseout::new(
+            summary.margin_closeout_nav,
+            summary.margin_closeout_margin_used,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qfin_api::oanda::Instrument;
+
+    use crate::{
+        conversion::CurrencyConverter,
+        margin::{MarginCalculator, MarginCloseout},
+        number,
+    };
+
+    fn instrument(name: &str) -> Instrument {
+        serde_json::from_str(&format!(
+            r#"
+            {{
+                "name": "{name}",
+                "type": "CURRENCY",
+                "displayName": "{name}",
+                "pipLocation": -4,
+                "displayPrecision": 5,
+                "tradeUnitsPrecision": 0,
+                "minimumTradeSize": "1",
+                "maximumTrailingStopDistance": "1.00000",
+                "minimumTrailingStopDistance": "0.00050",
+                "maximumPositionSize": "0",
+                "maximumOrderUnits": "100000",
+                "marginRate": "0.03125",
+                "guaranteedStopLossOrderMode": "DISABLED",
+                "tags": [],
+                "financing": {{
+                    "longRate": "0",
+                    "shortRate": "0",
+                    "financingDaysOfWeek": []
+                }}
+            }}
+            "#
+        ))
+        .unwrap()
+    }
+
+    fn converter() -> CurrencyConverter {
+        let mut converter = CurrencyConverter::new("USD".parse().unwrap());
+        converter.add_rate("USD_JPY".parse().unwrap(), number("128"));
+        converter
+    }
+
+    #[test]
+    fn test_margin_rate() {
+        let converter = converter();
+        let instrument = instrument("EUR_USD");
+
+        let calculator = MarginCalculator::new(&converter);
+        assert_eq!(number("0.03125"), calculator.margin_rate(&instrument));
+        assert_eq!(Some(number("32")), calculator.leverage(&instrument));
+
+        let calculator = calculator.with_account_margin_rate(number("0.05"));
+        assert_eq!(number("0.05"), calculator.margin_rate(&instrument));
+        assert_eq!(Some(number("20")), calculator.leverage(&instrument));
+
+        let calculator = calculator.with_account_margin_rate(number("0.02"));
+        assert_eq!(number("0.03125"), calculator.margin_rate(&instrument));
+    }
+
+    #[test]
+    fn test_required_margin() {
+        let converter = converter();
+        let calculator = MarginCalculator::new(&converter);
+
+        assert_eq!(
+            Some(number("390.625")),
+            calculator.required_margin(&instrument("EUR_USD"), number("10000"), number("1.25"))
+        );
+        assert_eq!(
+            Some(number("390.625")),
+            calculator.required_margin(&instrument("EUR_JPY"), number("-10000"), number("160"))
+        );
+        assert_eq!(
+            None,
+            calculator.required_margin(&instrument("EUR_GBP"), number("10000"), number("0.85"))
+        );
+        assert_eq!(
+            Some(false),
+            calculator.has_sufficient_margin(
+                &instrument("EUR_USD"),
+                number("10000"),
+                number("1.25"),
+                number("390")
+            )
+        );
+    }
+
+    #[test]
+    fn test_max_units() {
+        let converter = converter();
+        let calculator = MarginCalculator::new(&converter);
+        let instrument = instrument("EUR_USD");
+
+        assert_eq!(
+            Some(number("25600")),
+            calculator.max_units(&instrument, number("1.25"), number("1000"))
+        );
+        assert_eq!(
+            Some(number("25")),
+            calculator.max_units(&instrument, number("1.25"), number("1"))
+        );
+        assert_eq!(
+            Some(number("100000")),
+            calculator.max_units(&instrument, number("1.25"), number("1000000"))
+        );
+        assert_eq!(
+            Some(number("0")),
+            calculator.max_units(&instrument, number("1.25"), number("0.01"))
+        );
+    }
+
+    #[test]
+    fn test_margin_closeout() {
+        let closeout = MarginCloseout::new(number("1000"), number("500"));
+        assert_eq!(Some(number("0.25")), closeout.percent());
+        assert_eq!(number("250"), closeout.closeout_nav());
+        assert_eq!(number("750"), closeout.buffer());
+        assert!(!closeout.is_closeout());
+
+        let closeout = MarginCloseout::new(number("250"), number("500"));
+        assert_eq!(Some(number("1")), closeout.percent());
+        assert!(closeout.is_closeout());
+
+        assert_eq!(
+            None,
+            MarginCloseout::new(number("0"), number("500")).percent()
+        );
+        assert!(!MarginCloseout::new(number("0"), number("0")).is_closeout());
+    }
+}